@@ -1,24 +1,32 @@
+pub mod berlekamp_massey;
 pub mod bipartite_hamiltonian_cycle;
+pub mod bostan_mori;
 pub mod chromatic_number;
 pub mod determinant;
+pub mod divisor_transform;
 pub mod division_free_determinant;
 pub mod extreme_vertex_sets;
+pub mod fast_fourier_transform;
 pub mod givens_rotation;
 pub mod hafnian;
+pub mod karatsuba;
 pub mod karger_stein;
 pub mod larsch_algorithm;
 pub mod manually_gaussian_elimination;
 pub mod max_min_convolution;
 pub mod maximum_k_subarray;
+pub mod multidimensional_convolution;
 pub mod number_theoretic_transform;
 pub mod permutation_tree;
 pub mod polynomial_matrix_prod;
 pub mod pow;
 pub mod quick_select;
+pub mod relaxed_convolution;
 pub mod schoenhage_strassen;
 pub mod shortest_even_length_cycle;
 pub mod smawk;
 pub mod subset_convolution;
+pub mod three_prime_convolution;
 pub mod wildcard_matching;
 pub mod zeta_transform;
 
@@ -0,0 +1,112 @@
+/*
+
+Reference
+
+[1] Massey, J. (1969).
+    Shift-register synthesis and BCH decoding.
+    IEEE transactions on Information Theory, 15(1), 122-127.
+
+
+Description
+
+T: 体
+a: 長さ n の T の列
+
+Σ_{j=0}^{d} q_j a_{i-j} = 0 (d <= i < n) かつ q_0 = 1 を満たす
+d が最小の多項式 q(x) = Σ q_j x^j を返す。
+返り値の長さは d + 1 である。
+
+時間計算量: Θ(n^2) 回の T の演算
+
+a の先頭 2d 項が与えられていれば、d 次の線形漸化式は一意に復元される。
+
+*/
+
+use crate::other::algebraic::{one, zero, Field};
+use crate::other::Polynomial;
+
+pub fn berlekamp_massey<T>(a: &[T]) -> Polynomial<T>
+where
+    T: Field + Clone,
+{
+    let mut c: Vec<T> = vec![one()];
+    let mut b: Vec<T> = vec![one()];
+    let mut l = 0;
+    let mut m = 1;
+    let mut bd: T = one();
+    for i in 0..a.len() {
+        let mut d = a[i].clone();
+        for (c, a) in c[1..=l].iter().zip(a[..i].iter().rev()) {
+            d += c.clone() * a.clone();
+        }
+        if d.is_zero() {
+            m += 1;
+            continue;
+        }
+        let coef = d.clone() / bd.clone();
+        let t = c.clone();
+        if c.len() < b.len() + m {
+            c.resize(b.len() + m, zero());
+        }
+        for (c, b) in c[m..].iter_mut().zip(&b) {
+            *c -= coef.clone() * b.clone();
+        }
+        if 2 * l <= i {
+            l = i + 1 - l;
+            b = t;
+            bd = d;
+            m = 1;
+        } else {
+            m += 1;
+        }
+    }
+    c.resize(l + 1, zero());
+    c.into()
+}
+
+#[test]
+fn test_berlekamp_massey() {
+    use crate::other::fp::{Fp, ModInt};
+    use crate::other::rand::{rand_int, random};
+
+    let fib: Vec<Fp> = (0..10)
+        .scan((Fp::new(0), Fp::new(1)), |s, _| {
+            let r = s.0;
+            *s = (s.1, s.0 + s.1);
+            Some(r)
+        })
+        .collect();
+    assert_eq!(
+        berlekamp_massey(&fib).coef,
+        vec![Fp::new(1), -Fp::new(1), -Fp::new(1)]
+    );
+
+    fn test<const M: u32>(d: usize, n: usize) {
+        let c: Vec<ModInt<M>> = (0..d).map(|_| random()).collect();
+        let mut a: Vec<ModInt<M>> = (0..d).map(|_| random()).collect();
+        for i in d..n {
+            let v = (0..d).map(|j| c[j] * a[i - 1 - j]).sum();
+            a.push(v);
+        }
+        let q = berlekamp_massey(&a);
+        assert!(q.coef.len() <= d + 1);
+        assert_eq!(q.coef[0], ModInt(1));
+        for i in q.coef.len() - 1..n {
+            let s: ModInt<M> = q.coef.iter().enumerate().map(|(j, &q)| q * a[i - j]).sum();
+            assert_eq!(s, ModInt(0));
+        }
+        if M > 1000 && n >= 2 * d && q.coef.len() == d + 1 {
+            for j in 0..d {
+                assert_eq!(q.coef[j + 1], -c[j]);
+            }
+        }
+    }
+
+    for _ in 0..1000 {
+        let d = rand_int(0..10);
+        let n = rand_int(0..30);
+        test::<998244353>(d, n);
+        test::<2>(d, n);
+        test::<3>(d, n);
+    }
+}
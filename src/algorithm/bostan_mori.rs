@@ -0,0 +1,139 @@
+/*
+
+Reference
+
+[1] Bostan, A., & Mori, R. (2021).
+    A simple and fast algorithm for computing the N-th term of a linearly recurrent sequence.
+    In Symposium on Simplicity in Algorithms (SOSA) (pp. 118-132).
+
+
+Description
+
+p, q: F_M 上の多項式、deg(p) < deg(q) = d, q_0 ≠ 0
+a: F_M の列
+n: 非負整数
+
+bostan_mori(p, q, n): [x^n] p(x) / q(x)
+linear_recurrence_nth(a, q, n):
+    Σ_{j=0}^{d} q_j a_{i-j} = 0 (i >= d) で定まる列の第 n 項
+    a の先頭 d 項を初項として用いる。
+nth_term(a, n):
+    a に Berlekamp-Massey 法を適用して得た最小の漸化式で定まる列の第 n 項
+
+時間計算量
+bostan_mori, linear_recurrence_nth: O(M(d) log(n))
+nth_term: O(len(a)^2 + M(len(a)) log(n))
+ただし M(d) は多項式乗算の計算量
+
+p(x) / q(x) = p(x) q(-x) / q(x) q(-x) で分母が x^2 の多項式となることを用いて
+n を半分にしていく。
+Kitamasa 法と同じく x^n mod rev(q) を求めることに相当するが、除算が不要である。
+
+*/
+
+use crate::algorithm::berlekamp_massey::berlekamp_massey;
+use crate::other::algebraic::Zero;
+use crate::other::fp::ModInt;
+use crate::other::Polynomial;
+
+pub fn bostan_mori<const M: u32>(
+    p: Polynomial<ModInt<M>>,
+    q: Polynomial<ModInt<M>>,
+    mut n: u64,
+) -> ModInt<M> {
+    assert!(!q.coef.is_empty() && !q.coef[0].is_zero());
+    let (mut p, mut q) = (p, q);
+    while n != 0 {
+        let r: Polynomial<ModInt<M>> = q
+            .coef
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| if i % 2 == 0 { c } else { -c })
+            .collect();
+        let u = p * r.clone();
+        let v = q * r;
+        p = u
+            .coef
+            .into_iter()
+            .skip((n % 2) as usize)
+            .step_by(2)
+            .collect();
+        q = v.coef.into_iter().step_by(2).collect();
+        n /= 2;
+    }
+    match p.coef.first() {
+        Some(&c) => c / q.coef[0],
+        None => ModInt::zero(),
+    }
+}
+
+pub fn linear_recurrence_nth<const M: u32>(
+    a: &[ModInt<M>],
+    q: &Polynomial<ModInt<M>>,
+    n: u64,
+) -> ModInt<M> {
+    let d = q.coef.len() - 1;
+    assert!(a.len() >= d);
+    let a: Polynomial<ModInt<M>> = a[..d].iter().copied().collect();
+    let p = (q.clone() * a).truncated(d);
+    bostan_mori(p, q.clone(), n)
+}
+
+pub fn nth_term<const M: u32>(a: &[ModInt<M>], n: u64) -> ModInt<M> {
+    if n < a.len() as u64 {
+        return a[n as usize];
+    }
+    linear_recurrence_nth(a, &berlekamp_massey(a), n)
+}
+
+#[test]
+fn test_bostan_mori() {
+    use crate::other::fp::Fp;
+    use crate::other::rand::{rand_int, random};
+
+    let fib = [Fp::new(0), Fp::new(1)];
+    let q: Polynomial<Fp> = vec![Fp::new(1), -Fp::new(1), -Fp::new(1)].into();
+    // F_{10^18} mod 998244353 を倍加公式で求める
+    let mut x = (Fp::new(0), Fp::new(1));
+    for i in (0..60).rev() {
+        let (f, g) = x;
+        x = (f * (g + g - f), f * f + g * g);
+        if (1_000_000_000_000_000_000_u64 >> i) & 1 != 0 {
+            x = (x.1, x.0 + x.1);
+        }
+    }
+    assert_eq!(
+        linear_recurrence_nth(&fib, &q, 1_000_000_000_000_000_000),
+        x.0
+    );
+
+    fn test<const M: u32>(d: usize, n: usize, checks: usize) {
+        let c: Vec<ModInt<M>> = (0..d).map(|_| random()).collect();
+        let mut a: Vec<ModInt<M>> = (0..d).map(|_| random()).collect();
+        for i in d..n {
+            let v = (0..d).map(|j| c[j] * a[i - 1 - j]).sum();
+            a.push(v);
+        }
+        let q: Polynomial<ModInt<M>> = std::iter::once(ModInt::new(1))
+            .chain(c.iter().map(|&c| -c))
+            .collect();
+        for _ in 0..checks {
+            let i = rand_int(0..n);
+            assert_eq!(linear_recurrence_nth(&a[..d], &q, i as u64), a[i]);
+            if n >= 2 * d {
+                assert_eq!(nth_term(&a[..2 * d], i as u64), a[i]);
+            }
+        }
+    }
+
+    for _ in 0..300 {
+        let d = rand_int(0..10);
+        let n = rand_int(d + 1..60);
+        test::<998244353>(d, n, 10);
+        test::<1_000_000_007>(d, n, 10);
+    }
+    for _ in 0..3 {
+        let d = rand_int(0..300);
+        test::<998244353>(d, 2 * d + 100, 5);
+    }
+}
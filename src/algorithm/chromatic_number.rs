@@ -16,7 +16,7 @@ m: |E(G)|
 G の彩色数、すなわち任意の uv \in E(G) について c(u) \neq c(v) となる
 c: V(G) → {0,1,...,k-1} が存在する最小の k を計算する。
 そのような k が存在しない場合 None を返す。
-このアルゴリズムは O(n^2 log(n) / 2^w) の確率で本来より大きい値を返す。
+このアルゴリズムは O(n^2 log(n) / 2^30) の確率で本来より大きい値を返す。
 n < w を仮定している。
 
 時間計算量 O(m + 2^n log(n))
@@ -32,13 +32,17 @@ X を k 乗したのち包除原理を用いると計算することが出来る
 計算に用いる値は 2^(n^2) 程度に収まるため、多倍長整数を使えば
 O^~(2^n) のアルゴリズムが得られる。
 ランダムな素数 p を選び F_p 上で計算すれば、高い確率で正確な計算が可能である。
-この実装では実装の簡略化のため、p を固定している。
+この実装では p を [2^30, 2^31) から一様ランダムに選んでいる。
+計算中はスレッドの DynamicModInt の法を p に変更し、終了後に元に戻す。
 
 */
 
 pub fn chromatic_number(n: usize, edges: &[(usize, usize)]) -> Option<usize> {
     use crate::other::bit::{bsf, ceil_log2, WORD};
-    use crate::other::Fp;
+    use crate::other::dynamic_mod_int::with_modulus;
+    use crate::other::is_prime::is_prime;
+    use crate::other::rand::rand_int;
+    use crate::other::DynamicModInt as Fp;
 
     assert!(n < WORD);
 
@@ -60,38 +64,94 @@ pub fn chromatic_number(n: usize, edges: &[(usize, usize)]) -> Option<usize> {
         return Some(0);
     }
 
-    let mut x = vec![Fp::from(0); 1 << n];
-    x[0] = Fp::from(1);
-    for s in 1..1 << n {
-        let v = bsf(s);
-        x[s] = x[s & !(1 << v)] + x[s & !neighbor[v]];
-    }
+    let p = loop {
+        let p: u32 = rand_int(1 << 30..1 << 31);
+        if is_prime(p.into()) {
+            break p;
+        }
+    };
+    let res = with_modulus(p, || {
+        let mut x = vec![Fp::from(0); 1 << n];
+        x[0] = Fp::from(1);
+        for s in 1..1 << n {
+            let v = bsf(s);
+            x[s] = x[s & !(1 << v)] + x[s & !neighbor[v]];
+        }
 
-    let coef: Vec<_> = (0..1 << n)
-        .map(|s: usize| {
-            Fp::from(if (n - s.count_ones() as usize) % 2 == 0 {
-                1
-            } else {
-                -1
+        let coef: Vec<_> = (0..1 << n)
+            .map(|s: usize| {
+                Fp::from(if (n - s.count_ones() as usize) % 2 == 0 {
+                    1
+                } else {
+                    -1
+                })
             })
-        })
-        .collect();
+            .collect();
 
-    let mut pow = vec![x];
-    for _ in 1..ceil_log2(n) {
-        let next: Vec<_> = pow.last().unwrap().iter().map(|&t| t * t).collect();
-        pow.push(next);
-    }
+        let mut pow = vec![x];
+        for _ in 1..ceil_log2(n) {
+            let next: Vec<_> = pow.last().unwrap().iter().map(|&t| t * t).collect();
+            pow.push(next);
+        }
 
-    let mut res: usize = 0;
-    let mut a = vec![Fp::from(1); 1 << n];
-    for (i, p) in pow.into_iter().enumerate().rev() {
-        let next: Vec<_> = a.iter().zip(p).map(|(&a, p)| a * p).collect();
-        if coef.iter().zip(&next).map(|(&c, &t)| c * t).sum::<Fp>() == Fp::from(0) {
-            a = next;
-            res += 1 << i;
+        let mut res: usize = 0;
+        let mut a = vec![Fp::from(1); 1 << n];
+        for (i, p) in pow.into_iter().enumerate().rev() {
+            let next: Vec<_> = a.iter().zip(p).map(|(&a, p)| a * p).collect();
+            if coef.iter().zip(&next).map(|(&c, &t)| c * t).sum::<Fp>() == Fp::from(0) {
+                a = next;
+                res += 1 << i;
+            }
         }
-    }
+        res
+    });
 
     Some(res + 1)
 }
+
+#[test]
+fn test_chromatic_number() {
+    use crate::other::rand::{rand_from_ratio, rand_int};
+
+    fn naive(n: usize, edges: &[(usize, usize)]) -> Option<usize> {
+        if edges.iter().any(|&(u, v)| u == v) {
+            return None;
+        }
+        (0..=n).find(|&k| {
+            if k == 0 {
+                return n == 0;
+            }
+            let mut c = vec![0; n];
+            loop {
+                if edges.iter().all(|&(u, v)| c[u] != c[v]) {
+                    return true;
+                }
+                match (0..n).find(|&i| c[i] + 1 < k) {
+                    None => return false,
+                    Some(i) => {
+                        c[i] += 1;
+                        for c in &mut c[..i] {
+                            *c = 0;
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..8);
+        let (num, den) = (rand_int(0..5), 4);
+        let edges: Vec<_> = (0..n)
+            .flat_map(|u| (0..u).map(move |v| (u, v)))
+            .filter(|_| rand_from_ratio(num.min(den), den))
+            .collect();
+        assert_eq!(naive(n, &edges), chromatic_number(n, &edges));
+    }
+
+    // 呼び出し側の法は変更されない
+    use crate::other::dynamic_mod_int::{modulus, set_modulus};
+    set_modulus(1_000_000_007);
+    chromatic_number(3, &[(0, 1), (1, 2)]);
+    assert_eq!(modulus(), 1_000_000_007);
+}
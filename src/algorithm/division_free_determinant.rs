@@ -84,7 +84,7 @@ fn test_division_free_determinant() {
         let n = rand_int(1..n);
         let mut a: Vec<Vec<Fp>> = (0..n).map(|_| (0..n).map(|_| random()).collect()).collect();
         {
-            let mut v = vec![Fp(0); n];
+            let mut v = vec![Fp::new(0); n];
             for i in 0..n - 1 {
                 let c: Fp = random();
                 for j in 0..n {
@@ -93,6 +93,6 @@ fn test_division_free_determinant() {
             }
             a[n - 1] = v;
         }
-        assert_eq!(division_free_determinant(&a), Fp(0));
+        assert_eq!(division_free_determinant(&a), Fp::new(0));
     }
 }
@@ -0,0 +1,223 @@
+/*
+
+Description
+
+T: 可換半群 (zeta), Abel 群 (mobius), 環 (畳み込み)
+a, b: 長さ n + 1 の T の列 (添字 1, ..., n を用い、a_0 は無視する)
+
+divisor_zeta(a): b_k := Σ_{d|k} a_d で a を置き換える。
+divisor_mobius(a): divisor_zeta の逆変換
+multiple_zeta(a): b_k := Σ_{k|m, m<=n} a_m で a を置き換える。
+multiple_mobius(a): multiple_zeta の逆変換
+
+gcd_convolution(a, b): c_k := Σ_{gcd(i,j)=k} a_i b_j
+lcm_convolution(a, b): c_k := Σ_{lcm(i,j)=k} a_i b_j
+dirichlet_convolution(a, b): c_k := Σ_{ij=k} a_i b_j
+
+時間計算量
+zeta, mobius, gcd_convolution, lcm_convolution: Θ(n log(log(n))) 回の T の演算
+dirichlet_convolution: Θ(n log(n)) 回の T の演算
+
+各素数 p について、p 倍の関係にある添字間で累積和 (差分) をとる。
+これは素因数ごとの指数を座標とみなしたときの多次元累積和である。
+
+*/
+
+use crate::other::algebraic::{zero, Abelian, CommutativeSemigroup, Ring};
+
+pub fn divisor_zeta<T>(a: &mut [T])
+where
+    T: CommutativeSemigroup + Clone,
+{
+    let n = a.len().saturating_sub(1);
+    for p in primes(n) {
+        for k in 1..=n / p {
+            let t = a[k].clone();
+            a[k * p] += t;
+        }
+    }
+}
+
+pub fn divisor_mobius<T>(a: &mut [T])
+where
+    T: Abelian + Clone,
+{
+    let n = a.len().saturating_sub(1);
+    for p in primes(n) {
+        for k in (1..=n / p).rev() {
+            let t = a[k].clone();
+            a[k * p] -= t;
+        }
+    }
+}
+
+pub fn multiple_zeta<T>(a: &mut [T])
+where
+    T: CommutativeSemigroup + Clone,
+{
+    let n = a.len().saturating_sub(1);
+    for p in primes(n) {
+        for k in (1..=n / p).rev() {
+            let t = a[k * p].clone();
+            a[k] += t;
+        }
+    }
+}
+
+pub fn multiple_mobius<T>(a: &mut [T])
+where
+    T: Abelian + Clone,
+{
+    let n = a.len().saturating_sub(1);
+    for p in primes(n) {
+        for k in 1..=n / p {
+            let t = a[k * p].clone();
+            a[k] -= t;
+        }
+    }
+}
+
+pub fn gcd_convolution<T>(mut a: Vec<T>, mut b: Vec<T>) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    multiple_zeta(&mut a);
+    multiple_zeta(&mut b);
+    let mut c = pointwise(a, b);
+    multiple_mobius(&mut c);
+    c
+}
+
+pub fn lcm_convolution<T>(mut a: Vec<T>, mut b: Vec<T>) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    divisor_zeta(&mut a);
+    divisor_zeta(&mut b);
+    let mut c = pointwise(a, b);
+    divisor_mobius(&mut c);
+    c
+}
+
+pub fn dirichlet_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    let n = a.len().saturating_sub(1);
+    let mut c = vec![zero::<T>(); a.len()];
+    for i in 1..=n {
+        for j in 1..=n / i {
+            c[i * j] += a[i].clone() * b[j].clone();
+        }
+    }
+    c
+}
+
+fn pointwise<T>(a: Vec<T>, b: Vec<T>) -> Vec<T>
+where
+    T: Ring,
+{
+    let mut c: Vec<T> = a.into_iter().zip(b).map(|(a, b)| a * b).collect();
+    if let Some(c) = c.first_mut() {
+        *c = zero();
+    }
+    c
+}
+
+// n 以下の素数
+fn primes(n: usize) -> Vec<usize> {
+    let mut is_prime = vec![true; n + 1];
+    let mut res = Vec::new();
+    for i in 2..=n {
+        if is_prime[i] {
+            res.push(i);
+            for j in (i * i..=n).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+    }
+    res
+}
+
+#[test]
+fn test_divisor_transform() {
+    use crate::other::gcd::gcd;
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Fp;
+    use std::num::Wrapping;
+
+    assert_eq!(primes(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+    fn brute<T, F>(a: &[T], b: &[T], op: F) -> Vec<T>
+    where
+        T: Ring + Clone,
+        F: Fn(usize, usize) -> usize,
+    {
+        let mut c = vec![zero::<T>(); a.len()];
+        for (i, a) in a.iter().enumerate().skip(1) {
+            for (j, b) in b.iter().enumerate().skip(1) {
+                let k = op(i, j);
+                if k < c.len() {
+                    c[k] += a.clone() * b.clone();
+                }
+            }
+        }
+        c
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..60);
+        let mut a: Vec<Fp> = (0..=n).map(|_| random()).collect();
+        let mut b: Vec<Fp> = (0..=n).map(|_| random()).collect();
+        a[0] = Fp::new(0);
+        b[0] = Fp::new(0);
+
+        let mut z = a.clone();
+        divisor_zeta(&mut z);
+        for (k, &z) in z.iter().enumerate().skip(1) {
+            let s: Fp = (1..=k).filter(|d| k % d == 0).map(|d| a[d]).sum();
+            assert_eq!(z, s);
+        }
+        divisor_mobius(&mut z);
+        assert_eq!(z, a);
+
+        let mut z = a.clone();
+        multiple_zeta(&mut z);
+        for (k, &z) in z.iter().enumerate().skip(1) {
+            let s: Fp = (k..=n).step_by(k).map(|m| a[m]).sum();
+            assert_eq!(z, s);
+        }
+        multiple_mobius(&mut z);
+        assert_eq!(z, a);
+
+        let g = |i: usize, j: usize| gcd(i as u64, j as u64) as usize;
+        assert_eq!(gcd_convolution(a.clone(), b.clone()), brute(&a, &b, g));
+        assert_eq!(
+            lcm_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i / g(i, j) * j)
+        );
+        assert_eq!(dirichlet_convolution(&a, &b), brute(&a, &b, |i, j| i * j));
+
+        let a: Vec<Wrapping<i64>> = (0..=n).map(|_| Wrapping(random())).collect();
+        let b: Vec<Wrapping<i64>> = (0..=n).map(|_| Wrapping(random())).collect();
+        assert_eq!(gcd_convolution(a.clone(), b.clone()), brute(&a, &b, g));
+        assert_eq!(
+            lcm_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i / g(i, j) * j)
+        );
+    }
+
+    // μ * 1 = [k = 1]
+    let n = 100;
+    let mut mu = vec![Wrapping(0i64); n + 1];
+    mu[1] = Wrapping(1);
+    divisor_mobius(&mut mu);
+    let one = vec![Wrapping(1i64); n + 1];
+    let e = dirichlet_convolution(&mu, &one);
+    for (k, &e) in e.iter().enumerate().skip(1) {
+        assert_eq!(e, Wrapping((k == 1) as i64));
+    }
+}
@@ -0,0 +1,250 @@
+/*
+
+Description
+
+T: 浮動小数点数型
+a: 長さ 2^n の Complex<T> の列
+ω: e^(2πi / 2^n)
+
+fast_fourier_transform(a): b_i := Σ_k a_k ω^(ik) で a を置き換える。
+inverse_fast_fourier_transform(a): 上の逆変換
+
+real_convolution(a, b): 実数列 a, b に対し c_k := Σ_{i+j=k} a_i b_j
+split_convolution(a, b): 2^30 未満の整数列 a, b に対し c_k を正確に計算する。
+
+時間計算量: Θ(N log(N)) (N は変換あるいは出力の長さ)
+
+回転因子は cos, sin で直接計算した表を用い、誤差の蓄積を防ぐ。
+real_convolution は a + bi を一度変換し、共役対称性から a, b の変換を取り出す。
+split_convolution は各値を 15 bit ずつに分け、
+(a_0 + a_1 i) と (b_0 + b_1 i) の変換から 4 通りの積を 2 回の逆変換で得る。
+f64 で計算するので、出力の長さが 2^18 以下であることを仮定している。
+このとき丸め前の誤差は最悪でも 0.15 程度である (テスト参照)。
+
+*/
+
+use crate::other::algebraic::zero;
+use crate::other::Complex;
+use num_traits::float::Float;
+
+pub fn fast_fourier_transform<T>(a: &mut [Complex<T>])
+where
+    T: Float,
+{
+    transform(a, false);
+}
+
+pub fn inverse_fast_fourier_transform<T>(a: &mut [Complex<T>])
+where
+    T: Float,
+{
+    transform(a, true);
+    let n = T::from(a.len()).unwrap();
+    for a in a {
+        *a = *a / n;
+    }
+}
+
+pub fn real_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Float,
+{
+    let n = a.len();
+    let m = b.len();
+    if n == 0 || m == 0 {
+        return Vec::new();
+    }
+    let len = (n + m - 1).next_power_of_two();
+    let mut x = vec![zero::<Complex<T>>(); len];
+    for (x, &a) in x.iter_mut().zip(a) {
+        x.re = a;
+    }
+    for (x, &b) in x.iter_mut().zip(b) {
+        x.im = b;
+    }
+    fast_fourier_transform(&mut x);
+    let mut y: Vec<Complex<T>> = (0..len)
+        .map(|k| {
+            let (a, b) = unpack(&x, k);
+            a * b
+        })
+        .collect();
+    inverse_fast_fourier_transform(&mut y);
+    y.into_iter().take(n + m - 1).map(|y| y.re).collect()
+}
+
+pub fn split_convolution(a: &[u32], b: &[u32]) -> Vec<u128> {
+    split_convolution_with_error(a, b).0
+}
+
+// 結果と、丸める前の値と最も近い整数との差の最大値
+fn split_convolution_with_error(a: &[u32], b: &[u32]) -> (Vec<u128>, f64) {
+    const SHIFT: u32 = 15;
+    const MASK: u32 = (1 << SHIFT) - 1;
+    let n = a.len();
+    let m = b.len();
+    if n == 0 || m == 0 {
+        return (Vec::new(), 0.0);
+    }
+    assert!(a.iter().chain(b).all(|&x| x < 1 << (2 * SHIFT)));
+    let len = (n + m - 1).next_power_of_two();
+    let split = |a: &[u32]| {
+        let mut x = vec![zero::<Complex<f64>>(); len];
+        for (x, &a) in x.iter_mut().zip(a) {
+            *x = Complex::new((a & MASK) as f64, (a >> SHIFT) as f64);
+        }
+        fast_fourier_transform(&mut x);
+        x
+    };
+    let x = split(a);
+    let y = split(b);
+    let i = Complex::new(0.0, 1.0);
+    let mut p = Vec::with_capacity(len);
+    let mut q = Vec::with_capacity(len);
+    for k in 0..len {
+        let (a0, a1) = unpack(&x, k);
+        let (b0, b1) = unpack(&y, k);
+        p.push(a0 * b0 + i * a1 * b1);
+        q.push(a0 * b1 + a1 * b0);
+    }
+    inverse_fast_fourier_transform(&mut p);
+    inverse_fast_fourier_transform(&mut q);
+
+    let mut err: f64 = 0.0;
+    let mut round = |x: f64| {
+        let r = x.round();
+        err = err.max((x - r).abs());
+        r.max(0.0) as u128
+    };
+    let c = p
+        .into_iter()
+        .zip(q)
+        .take(n + m - 1)
+        .map(|(p, q)| round(p.re) + (round(q.re) << SHIFT) + (round(p.im) << (2 * SHIFT)))
+        .collect();
+    (c, err)
+}
+
+// x = FFT(a + bi) から (FFT(a)_k, FFT(b)_k) を取り出す
+fn unpack<T>(x: &[Complex<T>], k: usize) -> (Complex<T>, Complex<T>)
+where
+    T: Float,
+{
+    let n = x.len();
+    let s = x[k];
+    let t = x[(n - k) & (n - 1)].conj();
+    let half = T::from(0.5).unwrap();
+    let a = (s + t) * half;
+    let b = (s - t) * Complex::new(T::zero(), -half);
+    (a, b)
+}
+
+fn transform<T>(a: &mut [Complex<T>], inverse: bool)
+where
+    T: Float,
+{
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let tau = T::from(2.0 * std::f64::consts::PI).unwrap();
+    let sign = if inverse { -T::one() } else { T::one() };
+    let nt = T::from(n).unwrap();
+    let w: Vec<Complex<T>> = (0..n / 2)
+        .map(|j| Complex::cis(sign * tau * T::from(j).unwrap() / nt))
+        .collect();
+
+    let mut len = 1;
+    while len < n {
+        let step = n / (2 * len);
+        for block in a.chunks_exact_mut(2 * len) {
+            let (l, r) = block.split_at_mut(len);
+            for (k, (l, r)) in l.iter_mut().zip(r).enumerate() {
+                let u = *l;
+                let v = *r * w[k * step];
+                *l = u + v;
+                *r = u - v;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[test]
+fn test_fast_fourier_transform() {
+    use crate::algorithm::three_prime_convolution::u128_convolution;
+    use crate::other::rand::{rand_int, random};
+
+    for lg in 0..8 {
+        let n = 1 << lg;
+        let a: Vec<Complex<f64>> = (0..n)
+            .map(|_| Complex::new(random::<f64>() - 0.5, random::<f64>() - 0.5))
+            .collect();
+        let mut b = a.clone();
+        fast_fourier_transform(&mut b);
+        for (i, b) in b.iter().enumerate() {
+            let mut s = zero::<Complex<f64>>();
+            for (k, a) in a.iter().enumerate() {
+                let theta = 2.0 * std::f64::consts::PI * ((i * k) % n) as f64 / n as f64;
+                s += *a * Complex::cis(theta);
+            }
+            assert!((s - *b).abs() < 1e-9);
+        }
+        inverse_fast_fourier_transform(&mut b);
+        for (a, b) in a.iter().zip(&b) {
+            assert!((*a - *b).abs() < 1e-12);
+        }
+    }
+
+    for _ in 0..100 {
+        let n: usize = rand_int(0..100);
+        let m: usize = rand_int(0..100);
+        let a: Vec<f64> = (0..n).map(|_| random::<f64>() * 2.0 - 1.0).collect();
+        let b: Vec<f64> = (0..m).map(|_| random::<f64>() * 2.0 - 1.0).collect();
+        let c = real_convolution(&a, &b);
+        assert_eq!(c.len(), (n + m).saturating_sub(1).min(n * m));
+        for (k, c) in c.iter().enumerate() {
+            let s: f64 = (0..n)
+                .filter(|&i| k >= i && k - i < m)
+                .map(|i| a[i] * b[k - i])
+                .sum();
+            assert!((s - c).abs() < 1e-9);
+        }
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..100);
+        let m = rand_int(0..100);
+        let a: Vec<u32> = (0..n).map(|_| rand_int(0..1 << 30)).collect();
+        let b: Vec<u32> = (0..m).map(|_| rand_int(0..1 << 30)).collect();
+        let c = split_convolution(&a, &b);
+        let a: Vec<u64> = a.into_iter().map(u64::from).collect();
+        let b: Vec<u64> = b.into_iter().map(u64::from).collect();
+        assert_eq!(c, u128_convolution(&a, &b));
+    }
+
+    // 全ての値が 2^30 - 1 のとき誤差は最悪に近く、出力の長さ 2^k に対して
+    //   2^12: 1.7e-3, 2^16: 3.5e-2, 2^18: 0.14, 2^19: 0.31, 2^20: 0.5 (破綻)
+    // となる。誤差はおおよそ長さに比例する。
+    for &(lg, bound) in &[(12, 4e-3), (16, 0.07), (18, 0.25)] {
+        let n = 1 << (lg - 1);
+        let a = vec![(1 << 30) - 1; n];
+        let (c, err) = split_convolution_with_error(&a, &a);
+        assert!(err < bound, "{} {}", lg, err);
+        let x = ((1u128 << 30) - 1).pow(2);
+        for (k, c) in c.iter().enumerate() {
+            assert_eq!(*c, x * (k.min(2 * n - 2 - k) + 1) as u128);
+        }
+    }
+}
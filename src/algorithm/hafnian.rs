@@ -23,7 +23,6 @@ a のハフニアンを計算する。
 
 */
 
-
 use crate::other::algebraic::Ring;
 use crate::other::Polynomial;
 use itertools::zip;
@@ -105,5 +104,5 @@ fn test_hafnian() {
         make(vec![4, 5, 6, 0]),
     ];
 
-    assert_eq!(hafnian(&a), Fp(28));
+    assert_eq!(hafnian(&a), Fp::new(28));
 }
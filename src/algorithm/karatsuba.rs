@@ -0,0 +1,129 @@
+/*
+
+Reference
+
+[1] Karatsuba, A., & Ofman, Y. (1962).
+    Multiplication of many-digital numbers by automatic computers.
+    In Doklady Akademii Nauk (Vol. 145, No. 2, pp. 293-294).
+
+
+Description
+
+R: 環
+a: 長さ n の R の列
+b: 長さ m の R の列
+n >= m
+
+c_k := Σ_{i+j=k} a_i b_j を計算する。
+
+時間計算量: Θ(n m^(log_2(3) - 1)) 回の R の演算
+
+長い方を長さ m ごとに区切り、それぞれを Karatsuba 法で掛ける。
+短い入力に対しては愚直な方法を用いる。
+
+*/
+
+use crate::other::algebraic::Ring;
+
+const NAIVE_THRESHOLD: usize = 32;
+
+pub fn karatsuba<R>(a: &[R], b: &[R]) -> Vec<R>
+where
+    R: Ring + Clone,
+{
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut c = vec![R::zero(); a.len() + b.len() - 1];
+    add_product(a, b, &mut c);
+    c
+}
+
+// c += a b
+fn add_product<R>(a: &[R], b: &[R], c: &mut [R])
+where
+    R: Ring + Clone,
+{
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let n = a.len();
+    let m = b.len();
+
+    if m <= NAIVE_THRESHOLD {
+        for (i, a) in a.iter().enumerate() {
+            for (c, b) in c[i..].iter_mut().zip(b) {
+                *c += a.clone() * b.clone();
+            }
+        }
+        return;
+    }
+
+    if n != m {
+        for (i, a) in a.chunks(m).enumerate() {
+            add_product(a, b, &mut c[i * m..]);
+        }
+        return;
+    }
+
+    let h = n / 2;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let mut z1 = karatsuba(&sum(a0, a1), &sum(b0, b1));
+    for (z1, z0) in z1.iter_mut().zip(&z0) {
+        *z1 -= z0.clone();
+    }
+    for (z1, z2) in z1.iter_mut().zip(&z2) {
+        *z1 -= z2.clone();
+    }
+    for (c, z) in c.iter_mut().zip(z0) {
+        *c += z;
+    }
+    for (c, z) in c[h..].iter_mut().zip(z1) {
+        *c += z;
+    }
+    for (c, z) in c[2 * h..].iter_mut().zip(z2) {
+        *c += z;
+    }
+}
+
+// len(a) <= len(b)
+fn sum<R>(a: &[R], b: &[R]) -> Vec<R>
+where
+    R: Ring + Clone,
+{
+    let mut res = b.to_vec();
+    for (r, a) in res.iter_mut().zip(a) {
+        *r += a.clone();
+    }
+    res
+}
+
+#[test]
+fn test_karatsuba() {
+    use crate::other::rand::{rand_int, random};
+    use std::num::Wrapping;
+
+    fn naive(a: &[Wrapping<u64>], b: &[Wrapping<u64>]) -> Vec<Wrapping<u64>> {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut c = vec![Wrapping(0); a.len() + b.len() - 1];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                c[i + j] += a * b;
+            }
+        }
+        c
+    }
+
+    for &(n_max, q) in &[(10, 1000), (200, 100), (2000, 3)] {
+        for _ in 0..q {
+            let n = rand_int(0..n_max);
+            let m = rand_int(0..n_max);
+            let a: Vec<Wrapping<u64>> = (0..n).map(|_| Wrapping(random())).collect();
+            let b: Vec<Wrapping<u64>> = (0..m).map(|_| Wrapping(random())).collect();
+            assert_eq!(karatsuba(&a, &b), naive(&a, &b));
+        }
+    }
+}
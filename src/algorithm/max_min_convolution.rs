@@ -59,20 +59,20 @@ where
     let mut b_ = vec![false; m].into_boxed_slice();
     let mut res: Box<[Option<&'a T>]> = vec![None; n + m - 1].into_boxed_slice();
     for block in idx.chunks(block_size) {
-        let mut a_t: Vec<_> = a_.iter().map(|&x| Fp(if x { 1 } else { 0 })).collect();
-        let mut b_t: Vec<_> = b_.iter().map(|&x| Fp(if x { 1 } else { 0 })).collect();
+        let mut a_t: Vec<_> = a_.iter().map(|&x| Fp::new(if x { 1 } else { 0 })).collect();
+        let mut b_t: Vec<_> = b_.iter().map(|&x| Fp::new(if x { 1 } else { 0 })).collect();
         for &e in block {
             match e {
-                Elem::A(i) => a_t[i] = Fp(1),
-                Elem::B(j) => b_t[j] = Fp(1),
+                Elem::A(i) => a_t[i] = Fp::new(1),
+                Elem::B(j) => b_t[j] = Fp::new(1),
             }
         }
 
         use crate::algorithm::number_theoretic_transform::fp_convolution;
 
-        let conv = fp_convolution(Fp(3), a_t, b_t);
+        let conv = fp_convolution(a_t, b_t);
         let check = (0..n + m - 1)
-            .filter(|&i| res[i].is_none() && conv[i] != Fp(0))
+            .filter(|&i| res[i].is_none() && conv[i] != Fp::new(0))
             .collect::<Vec<_>>()
             .into_boxed_slice();
         for &e in block {
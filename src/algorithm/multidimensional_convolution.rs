@@ -0,0 +1,367 @@
+/*
+
+Description
+
+a: 形 (n_0, ..., n_{k-1}) の F_M の多次元配列 (行優先で平坦化したもの)
+b: 形 (m_0, ..., m_{k-1}) の F_M の多次元配列
+
+multidimensional_convolution(a, a_shape, b, b_shape):
+    c_x := Σ_{y+z=x} a_y b_z を、形 (n_0 + m_0 - 1, ...) の配列として返す。
+truncated_multivariate_convolution(a, b, shape):
+    a, b がともに形 shape のとき、c_x := Σ_{y+z=x} a_y b_z を形 shape の範囲で返す。
+    すなわち mod (x_0^(n_0), ..., x_{k-1}^(n_{k-1})) での積である。
+total_degree_convolution(a, b, k, n):
+    a, b が k 変数の全次数 n 未満の単項式の係数を total_degree_monomials(k, n) の順に
+    並べたもの (長さ N = C(n + k - 1, k)) のとき、積を全次数 n 未満で打ち切ったもの
+    n^(k-1) < M を仮定している。
+total_degree_monomials(k, n): 全次数 n 未満の単項式の指数を、全次数の昇順、
+                              同じ全次数の中では辞書順に並べたもの
+
+時間計算量 (N は出力の大きさ)
+multidimensional_convolution: O(N log(N))
+truncated_multivariate_convolution: O(k N log(N) + k^2 N)
+total_degree_convolution: O(k N log(N) + N log(N)^2)
+
+全体の積は出力の形を基数とした Kronecker 置換で一次元の畳み込みに帰着する。
+
+打ち切った積は、形 shape を基数とする添字 i に対し
+rank(i) := Σ_{j>=1} floor(i / (n_j ⋯ n_{k-1})) mod k とおくと、
+一次元の和 y + z の繰り上がりの回数が rank(y) + rank(z) - rank(y + z) (mod k) に
+等しいことを用いる。繰り上がりは高々 k - 1 回なので、subset_convolution で
+popcount を次数とする多項式を用いるのと同様に、rank ごとに分けて畳み込み
+rank が合う項のみを取り出せばよい。
+
+全次数で打ち切った積は、全次数 Σ e_j を rank として x^e を t^|e| x^e に置き換え、
+t について mod t^n で打ち切った積を求めることに帰着する。
+t^d の係数は x_{k-1} = 1 とすると x_0, ..., x_{k-2} の d 次以下の多項式であり、
+その単項式は s_d = C(d + k - 1, k - 1) 個である。
+g を原始根、p_j = g^(n^j) として点 (p_0^i, ..., p_{k-2}^i) で評価すると、
+単項式 x^e の値は w_e^i (w_e = g^(Σ_j e_j n^j)) となり、w_e は相異なる。
+よって i < s_d の点での値から転置 Vandermonde 行列の方程式を解いて係数が復元できる。
+各 t^d の係数を i < s_(n-1) の点で評価し、点ごとに t についての積をとって復元する。
+評価は Σ_e f_e / (1 - w_e x) の展開、復元はその逆で、いずれも多点評価で計算する。
+多点評価の定数倍が重いので、k が小さいときは truncated_multivariate_convolution で
+shape = (n, ..., n) として Σ e_j < n の項を読む方が速い (k <= 3 程度)。
+
+*/
+
+use crate::algorithm::number_theoretic_transform::{fp_convolution, with_plan, NttPlan};
+use crate::other::algebraic::{one, zero};
+use crate::other::fp::ModInt;
+use crate::other::Polynomial;
+
+pub fn multidimensional_convolution<const M: u32>(
+    a: &[ModInt<M>],
+    a_shape: &[usize],
+    b: &[ModInt<M>],
+    b_shape: &[usize],
+) -> Vec<ModInt<M>> {
+    assert_eq!(a_shape.len(), b_shape.len());
+    assert_eq!(a.len(), a_shape.iter().product::<usize>());
+    assert_eq!(b.len(), b_shape.iter().product::<usize>());
+    let shape: Vec<usize> = a_shape
+        .iter()
+        .zip(b_shape)
+        .map(|(&n, &m)| if n == 0 || m == 0 { 0 } else { n + m - 1 })
+        .collect();
+    let len = shape.iter().product();
+    if len == 0 {
+        return Vec::new();
+    }
+    let (a, b) = (embed(a, a_shape, &shape), embed(b, b_shape, &shape));
+    let mut c = with_plan(|plan: &mut NttPlan<M>| plan.convolution(&a, &b));
+    c.resize(len, zero());
+    c
+}
+
+pub fn truncated_multivariate_convolution<const M: u32>(
+    a: &[ModInt<M>],
+    b: &[ModInt<M>],
+    shape: &[usize],
+) -> Vec<ModInt<M>> {
+    let n: usize = shape.iter().product();
+    assert_eq!(a.len(), n);
+    assert_eq!(b.len(), n);
+    if n == 0 {
+        return Vec::new();
+    }
+    let k = shape.len().max(1);
+    let mut p = n;
+    let divisors: Vec<usize> = shape[..shape.len().saturating_sub(1)]
+        .iter()
+        .map(|&s| {
+            p /= s;
+            p
+        })
+        .collect();
+    // divisors[j] = n_{j+1} ⋯ n_{k-1} であり、i / n = 0 なので j = 0 の項は省いている
+    let rank: Vec<usize> = (0..n)
+        .map(|i| divisors.iter().map(|&d| i / d).sum::<usize>() % k)
+        .collect();
+
+    let len = (2 * n - 1).next_power_of_two();
+    with_plan(|plan: &mut NttPlan<M>| {
+        let mut split = |a: &[ModInt<M>]| {
+            let mut res = vec![vec![zero::<ModInt<M>>(); len]; k];
+            for (i, &a) in a.iter().enumerate() {
+                res[rank[i]][i] = a;
+            }
+            for r in &mut res {
+                plan.forward(r);
+            }
+            res
+        };
+        let fa = split(a);
+        let fb = split(b);
+        let mut fc = vec![vec![zero::<ModInt<M>>(); len]; k];
+        for (p, fa) in fa.iter().enumerate() {
+            for (q, fb) in fb.iter().enumerate() {
+                for ((c, &a), &b) in fc[(p + q) % k].iter_mut().zip(fa).zip(fb) {
+                    *c += a * b;
+                }
+            }
+        }
+        for c in &mut fc {
+            plan.inverse(c);
+        }
+        (0..n).map(|i| fc[rank[i]][i]).collect()
+    })
+}
+
+pub fn total_degree_convolution<const M: u32>(
+    a: &[ModInt<M>],
+    b: &[ModInt<M>],
+    k: usize,
+    n: usize,
+) -> Vec<ModInt<M>> {
+    let mono = total_degree_monomials(k, n);
+    assert_eq!(a.len(), mono.len());
+    assert_eq!(b.len(), mono.len());
+    if mono.is_empty() {
+        return Vec::new();
+    }
+    if k == 0 {
+        return vec![a[0] * b[0]];
+    }
+    assert!((n as u64)
+        .checked_pow((k - 1) as u32)
+        .is_some_and(|p| p < M.into()));
+
+    // 全次数 d の単項式は mono[start[d]..start[d + 1]]
+    let mut start = vec![0; n + 1];
+    for e in &mono {
+        start[e.iter().sum::<usize>() + 1] += 1;
+    }
+    for d in 0..n {
+        start[d + 1] += start[d];
+    }
+    let l = start[n] - start[n - 1];
+    let g = ModInt::<M>::primitive_root();
+    let w: Vec<ModInt<M>> = mono
+        .iter()
+        .map(|e| {
+            g.pow(
+                e[..k - 1]
+                    .iter()
+                    .rev()
+                    .fold(0, |s, &x| s * n as u64 + x as u64),
+            )
+        })
+        .collect();
+
+    // va[i][d], vb[i][d]: t^d の係数の i 番目の点での値
+    let mut va = vec![vec![zero::<ModInt<M>>(); n]; l];
+    let mut vb = va.clone();
+    let mut den = Vec::with_capacity(n);
+    for d in 0..n {
+        let r = start[d]..start[d + 1];
+        let (pa, q) = partial_fraction_sum(&a[r.clone()], &w[r.clone()]);
+        let (pb, _) = partial_fraction_sum(&b[r.clone()], &w[r]);
+        let qi = q.inv(l);
+        for (va, x) in va.iter_mut().zip((pa * qi.clone()).truncated(l)) {
+            va[d] = x;
+        }
+        for (vb, x) in vb.iter_mut().zip((pb * qi).truncated(l)) {
+            vb[d] = x;
+        }
+        den.push(q);
+    }
+    let vc: Vec<Vec<ModInt<M>>> = va
+        .into_iter()
+        .zip(vb)
+        .map(|(a, b)| fp_convolution(a, b))
+        .collect();
+
+    let mut res = Vec::with_capacity(mono.len());
+    for (d, q) in den.into_iter().enumerate() {
+        let w = &w[start[d]..start[d + 1]];
+        let s = w.len();
+        // Σ_e c_e / (1 - w_e x) = v(x) + O(x^s) より、分子は v q mod x^s
+        let v: Polynomial<ModInt<M>> = vc[..s].iter().map(|v| v[d]).collect();
+        let mut p = (v * q.clone()).truncated(s);
+        p.coef.reverse();
+        // c_e = x^(s-1) p(1 / x) / (d/dx Π (x - w_e)) at x = w_e
+        let mut r = q;
+        r.coef.reverse();
+        let num = p.multipoint_evaluate(w);
+        let den = r.derivative().multipoint_evaluate(w);
+        res.extend(num.into_iter().zip(den).map(|(x, y)| x / y));
+    }
+    res
+}
+
+pub fn total_degree_monomials(k: usize, n: usize) -> Vec<Vec<usize>> {
+    let mut res = Vec::new();
+    let mut e = vec![0; k];
+    for d in 0..n {
+        push_monomials(&mut e, 0, d, &mut res);
+    }
+    res
+}
+
+// e[i..] を和が d となるように辞書順に埋めたものを res に加える
+fn push_monomials(e: &mut Vec<usize>, i: usize, d: usize, res: &mut Vec<Vec<usize>>) {
+    if i + 1 >= e.len() {
+        if i < e.len() {
+            e[i] = d;
+            res.push(e.clone());
+        } else if d == 0 {
+            res.push(e.clone());
+        }
+        return;
+    }
+    for x in 0..=d {
+        e[i] = x;
+        push_monomials(e, i + 1, d - x, res);
+    }
+}
+
+// (Σ_j f_j Π_{l≠j} (1 - w_l x), Π_l (1 - w_l x))
+fn partial_fraction_sum<const M: u32>(
+    f: &[ModInt<M>],
+    w: &[ModInt<M>],
+) -> (Polynomial<ModInt<M>>, Polynomial<ModInt<M>>) {
+    if w.len() == 1 {
+        return (f[0].into(), vec![one(), -w[0]].into());
+    }
+    let h = w.len() / 2;
+    let (p0, q0) = partial_fraction_sum(&f[..h], &w[..h]);
+    let (p1, q1) = partial_fraction_sum(&f[h..], &w[h..]);
+    (p0 * q1.clone() + p1 * q0.clone(), q0 * q1)
+}
+
+// 形 from の配列 a を、形 to の配列の同じ位置に置いたもの (末尾の 0 は省く)
+fn embed<const M: u32>(a: &[ModInt<M>], from: &[usize], to: &[usize]) -> Vec<ModInt<M>> {
+    let pos = |mut i: usize| {
+        let mut res = 0;
+        let mut stride = 1;
+        for (&f, &t) in from.iter().zip(to).rev() {
+            res += i % f * stride;
+            i /= f;
+            stride *= t;
+        }
+        res
+    };
+    let mut res = vec![zero(); a.len().checked_sub(1).map_or(0, |i| pos(i) + 1)];
+    for (i, &a) in a.iter().enumerate() {
+        res[pos(i)] = a;
+    }
+    res
+}
+
+#[test]
+fn test_multidimensional_convolution() {
+    use crate::other::fp::Fp;
+    use crate::other::rand::{rand_int, random};
+
+    fn digits(mut i: usize, shape: &[usize]) -> Vec<usize> {
+        let mut res = vec![0; shape.len()];
+        for (r, &s) in res.iter_mut().zip(shape).rev() {
+            *r = i % s;
+            i /= s;
+        }
+        res
+    }
+
+    fn index(x: &[usize], shape: &[usize]) -> Option<usize> {
+        let mut res = 0;
+        for (&x, &s) in x.iter().zip(shape) {
+            if x >= s {
+                return None;
+            }
+            res = res * s + x;
+        }
+        Some(res)
+    }
+
+    fn naive(a: &[Fp], a_shape: &[usize], b: &[Fp], b_shape: &[usize], shape: &[usize]) -> Vec<Fp> {
+        let mut c = vec![Fp::new(0); shape.iter().product()];
+        for (i, &a) in a.iter().enumerate() {
+            let y = digits(i, a_shape);
+            for (j, &b) in b.iter().enumerate() {
+                let z = digits(j, b_shape);
+                let x: Vec<usize> = y.iter().zip(&z).map(|(y, z)| y + z).collect();
+                if let Some(k) = index(&x, shape) {
+                    c[k] += a * b;
+                }
+            }
+        }
+        c
+    }
+
+    for _ in 0..200 {
+        let k = rand_int(0..4);
+        let a_shape: Vec<usize> = (0..k).map(|_| rand_int(0..5)).collect();
+        let b_shape: Vec<usize> = (0..k).map(|_| rand_int(0..5)).collect();
+        let a: Vec<Fp> = (0..a_shape.iter().product()).map(|_| random()).collect();
+        let b: Vec<Fp> = (0..b_shape.iter().product()).map(|_| random()).collect();
+        let shape: Vec<usize> = a_shape
+            .iter()
+            .zip(&b_shape)
+            .map(|(&n, &m)| if n == 0 || m == 0 { 0 } else { n + m - 1 })
+            .collect();
+        assert_eq!(
+            multidimensional_convolution(&a, &a_shape, &b, &b_shape),
+            naive(&a, &a_shape, &b, &b_shape, &shape)
+        );
+
+        let b: Vec<Fp> = (0..a.len()).map(|_| random()).collect();
+        assert_eq!(
+            truncated_multivariate_convolution(&a, &b, &a_shape),
+            naive(&a, &a_shape, &b, &a_shape, &a_shape)
+        );
+    }
+
+    for _ in 0..100 {
+        let k = rand_int(0..5);
+        let n = rand_int(0..[20, 20, 12, 6, 4][k]);
+        let mono = total_degree_monomials(k, n);
+        let a: Vec<Fp> = mono.iter().map(|_| random()).collect();
+        let b: Vec<Fp> = mono.iter().map(|_| random()).collect();
+        let mut naive = vec![Fp::new(0); mono.len()];
+        for (y, &a) in mono.iter().zip(&a) {
+            for (z, &b) in mono.iter().zip(&b) {
+                let x: Vec<usize> = y.iter().zip(z).map(|(y, z)| y + z).collect();
+                if let Some(i) = mono.iter().position(|e| *e == x) {
+                    naive[i] += a * b;
+                }
+            }
+        }
+        assert_eq!(total_degree_convolution(&a, &b, k, n), naive);
+    }
+
+    // (1 + x + y)^2 を全次数 2 未満で打ち切ると 1 + 2x + 2y
+    assert_eq!(
+        total_degree_monomials(2, 2),
+        vec![vec![0, 0], vec![0, 1], vec![1, 0]]
+    );
+    let f = vec![Fp::new(1); 3];
+    let g = total_degree_convolution(&f, &f, 2, 2);
+    assert_eq!(g, vec![Fp::new(1), Fp::new(2), Fp::new(2)]);
+
+    // (1 + x + y)^2 mod (x^2, y^3)
+    let f: Vec<Fp> = [1, 1, 0, 1, 0, 0].iter().map(|&x| Fp::new(x)).collect();
+    let g = truncated_multivariate_convolution(&f, &f, &[2, 3]);
+    let ans: Vec<Fp> = [1, 2, 1, 2, 2, 0].iter().map(|&x| Fp::new(x)).collect();
+    assert_eq!(g, ans);
+}
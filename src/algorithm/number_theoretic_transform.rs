@@ -2,91 +2,288 @@
 
 Description
 
-g: Fp の原始根
-a: 長さ 2^n の Fp の列
+a: 長さ 2^n の F_M の列
 r: 1 の原始 2^n 乗根
 
 b_i := Σ_k a_k r^(ik) を計算する。
 
-時間計算量: Θ(2^n n + log(p))
+時間計算量: Θ(2^n n + log(M))
 
-Fp 上の高速フーリエ変換。
+原始根は ModInt::primitive_root を用いて自動的に求める。
+
+F_M 上の高速フーリエ変換。
 法などにはさらに制限があるが、特に記述しない。
 
+NttPlan は回転因子の表をブロックの大きさごとに保持し、
+同じ法での変換や畳み込みを繰り返す際に使い回す。
+number_theoretic_transform などの関数は、法ごとにスレッドローカルな NttPlan を共有する。
+バタフライは radix-4 である。
+forward_montgomery, inverse_montgomery は MontgomeryModInt の列を、
+追加の領域を使わずその場で変換する。
+ModInt の列に対する forward, inverse は、Montgomery 表現との変換に長さ 2^n の一時領域を用い、
+convolution は |a| + |b| - 1 以上の最小の 2 冪の長さの作業領域を 2 つ用いる。
+内部では周波数間引きで順変換し、時間間引きで逆変換するので、
+畳み込みではビット反転の並べ替えが不要である。
+用いる原始根は with_root で指定することもできる。
+内部では Montgomery 表現に変換して計算する。
+
 */
 
 use crate::other::algebraic::{one, zero};
-use crate::other::{fp::P, Fp};
-use std::mem::swap;
+use crate::other::fp::ModInt;
+use crate::other::montgomery_mod_int::MontgomeryModInt;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::thread_local;
 
-pub fn number_theoretic_transform(g: Fp, a: &mut [Fp]) {
-    let n = a.len();
-    assert!(n.is_power_of_two());
-    let mask = n - 1;
-    let lgn = n.trailing_zeros();
-    let root = g.pow(((P - 1) / n as u32).into());
-    let mut a = a;
-    let mut b = vec![Fp(0); n].into_boxed_slice();
-    let mut b: &mut [Fp] = &mut b;
-
-    for i_ in (0..lgn).rev() {
-        swap(&mut a, &mut b);
-        let i: usize = 1 << i_;
-        let mut c: Fp = one();
-        let d = root.pow(i as u64);
-
-        for j in (0..n).step_by(i) {
-            let l = j * 2 & mask;
-            let r = l + i;
-            for k in 0..i {
-                a[j + k] = b[l + k] + b[r + k] * c;
+type Mint<const M: u32> = MontgomeryModInt<M>;
+
+pub fn number_theoretic_transform<const M: u32>(a: &mut [ModInt<M>]) {
+    with_plan(|plan: &mut NttPlan<M>| plan.forward(a));
+}
+
+pub fn inverse_number_theoretic_transform<const M: u32>(a: &mut [ModInt<M>]) {
+    with_plan(|plan: &mut NttPlan<M>| plan.inverse(a));
+}
+
+pub fn fp_convolution<const M: u32>(a: Vec<ModInt<M>>, b: Vec<ModInt<M>>) -> Vec<ModInt<M>> {
+    with_plan(|plan: &mut NttPlan<M>| plan.convolution(&a, &b))
+}
+
+thread_local! {
+    // 法 M に対する NttPlan<M>
+    static PLANS: RefCell<HashMap<u32, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+// 法 M のスレッドローカルな NttPlan を用いて f を呼ぶ
+pub fn with_plan<const M: u32, R>(f: impl FnOnce(&mut NttPlan<M>) -> R) -> R {
+    // f が panic しても NttPlan を戻す
+    struct Restore<const M: u32>(Option<Box<NttPlan<M>>>);
+    impl<const M: u32> Drop for Restore<M> {
+        fn drop(&mut self) {
+            if let Some(plan) = self.0.take() {
+                PLANS.with(|p| p.borrow_mut().insert(M, plan));
             }
-            c *= d;
         }
     }
+    // f の中で with_plan が呼ばれても良いように、一旦取り出す
+    let plan = PLANS.with(|p| p.borrow_mut().remove(&M));
+    let mut plan = Restore(Some(match plan {
+        Some(plan) => plan.downcast::<NttPlan<M>>().unwrap(),
+        None => Box::default(),
+    }));
+    f(plan.0.as_mut().unwrap())
+}
 
-    if lgn % 2 == 1 {
-        b.copy_from_slice(a);
-    }
+pub struct NttPlan<const M: u32> {
+    root: ModInt<M>,
+    // twiddle[k][j] = (w^j, w^2j, w^3j) (j < 2^(k-2))、ただし w は 1 の原始 2^k 乗根
+    twiddle: Vec<Vec<[Mint<M>; 3]>>,
+    // inv_twiddle は w の代わりに w^-1 を用いたもの
+    inv_twiddle: Vec<Vec<[Mint<M>; 3]>>,
+    // 1 の原始 4 乗根とその逆元
+    imag: Mint<M>,
+    inv_imag: Mint<M>,
 }
 
-pub fn inverse_number_theoretic_transform(g: Fp, a: &mut [Fp]) {
-    number_theoretic_transform(g, a);
-    a[1..].reverse();
-    let inv = one::<Fp>() / Fp::from(a.len());
-    for a in a {
-        *a *= inv;
+impl<const M: u32> NttPlan<M> {
+    pub fn new() -> Self {
+        Self::with_root(ModInt::primitive_root())
+    }
+
+    pub fn with_root(root: ModInt<M>) -> Self {
+        let imag = root.pow(((M - 1) / 4).into());
+        Self {
+            root,
+            twiddle: vec![Vec::new(); 2],
+            inv_twiddle: vec![Vec::new(); 2],
+            imag: imag.into(),
+            inv_imag: (one::<ModInt<M>>() / imag).into(),
+        }
+    }
+
+    pub fn forward(&mut self, a: &mut [ModInt<M>]) {
+        let mut b = to_montgomery(a);
+        self.forward_montgomery(&mut b);
+        from_montgomery(a, b);
+    }
+
+    pub fn inverse(&mut self, a: &mut [ModInt<M>]) {
+        let mut b = to_montgomery(a);
+        self.inverse_montgomery(&mut b);
+        from_montgomery(a, b);
+    }
+
+    pub fn forward_montgomery(&mut self, a: &mut [Mint<M>]) {
+        self.dif(a);
+        bit_reverse(a);
+    }
+
+    pub fn inverse_montgomery(&mut self, a: &mut [Mint<M>]) {
+        bit_reverse(a);
+        self.dit(a);
+    }
+
+    pub fn convolution(&mut self, a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+        let n = a.len();
+        let m = b.len();
+        if n == 0 || m == 0 {
+            return Vec::new();
+        }
+        let r = (n + m - 1).next_power_of_two();
+        let mut a = to_montgomery(a);
+        a.resize(r, zero());
+        self.dif(&mut a);
+        let mut b = to_montgomery(b);
+        b.resize(r, zero());
+        self.dif(&mut b);
+        for (a, b) in a.iter_mut().zip(b) {
+            *a *= b;
+        }
+        self.dit(&mut a);
+        a.into_iter().take(n + m - 1).map(Into::into).collect()
+    }
+
+    fn prepare(&mut self, lg: usize) {
+        assert!(lg as u32 <= (M - 1).trailing_zeros());
+        while self.twiddle.len() <= lg {
+            let k = self.twiddle.len();
+            let w = self.root.pow(((M - 1) >> k).into());
+            self.twiddle.push(table(w.into(), 1 << (k - 2)));
+            self.inv_twiddle
+                .push(table((one::<ModInt<M>>() / w).into(), 1 << (k - 2)));
+        }
+    }
+
+    // 自然な順序の入力を、ビット反転した順序の出力に変換する
+    fn dif(&mut self, a: &mut [Mint<M>]) {
+        let n = a.len();
+        assert!(n.is_power_of_two());
+        let lg = n.trailing_zeros() as usize;
+        self.prepare(lg);
+        let mut k = lg;
+        while k >= 2 {
+            let m = 1 << (k - 2);
+            let tw = &self.twiddle[k];
+            let imag = self.imag;
+            for block in a.chunks_exact_mut(4 * m) {
+                for (j, &[w1, w2, w3]) in tw.iter().enumerate() {
+                    let a0 = block[j];
+                    let a1 = block[j + m];
+                    let a2 = block[j + 2 * m];
+                    let a3 = block[j + 3 * m];
+                    let b0 = a0 + a2;
+                    let b1 = a1 + a3;
+                    let d0 = a0 - a2;
+                    let d1 = (a1 - a3) * imag;
+                    block[j] = b0 + b1;
+                    block[j + m] = (b0 - b1) * w2;
+                    block[j + 2 * m] = (d0 + d1) * w1;
+                    block[j + 3 * m] = (d0 - d1) * w3;
+                }
+            }
+            k -= 2;
+        }
+        if k == 1 {
+            for p in a.chunks_exact_mut(2) {
+                let (u, v) = (p[0], p[1]);
+                p[0] = u + v;
+                p[1] = u - v;
+            }
+        }
+    }
+
+    // dif の逆変換
+    fn dit(&mut self, a: &mut [Mint<M>]) {
+        let n = a.len();
+        assert!(n.is_power_of_two());
+        let lg = n.trailing_zeros() as usize;
+        self.prepare(lg);
+        if lg % 2 == 1 {
+            for p in a.chunks_exact_mut(2) {
+                let (u, v) = (p[0], p[1]);
+                p[0] = u + v;
+                p[1] = u - v;
+            }
+        }
+        for k in (lg % 2 + 2..=lg).step_by(2) {
+            let m = 1 << (k - 2);
+            let tw = &self.inv_twiddle[k];
+            let imag = self.inv_imag;
+            for block in a.chunks_exact_mut(4 * m) {
+                for (j, &[w1, w2, w3]) in tw.iter().enumerate() {
+                    let c0 = block[j];
+                    let c1 = block[j + m] * w2;
+                    let c2 = block[j + 2 * m] * w1;
+                    let c3 = block[j + 3 * m] * w3;
+                    let b0 = c0 + c1;
+                    let b1 = c0 - c1;
+                    let d0 = c2 + c3;
+                    let d1 = (c2 - c3) * imag;
+                    block[j] = b0 + d0;
+                    block[j + m] = b1 + d1;
+                    block[j + 2 * m] = b0 - d0;
+                    block[j + 3 * m] = b1 - d1;
+                }
+            }
+        }
+        let inv = Mint::from(one::<ModInt<M>>() / ModInt::from(n));
+        for a in a {
+            *a *= inv;
+        }
     }
 }
 
-pub fn fp_convolution(g: Fp, mut a: Vec<Fp>, mut b: Vec<Fp>) -> Vec<Fp> {
-    let n = a.len();
-    let m = b.len();
-    if n == 0 || m == 0 {
-        return Vec::new();
+impl<const M: u32> Default for NttPlan<M> {
+    fn default() -> Self {
+        Self::new()
     }
-    let r = (n + m - 1).next_power_of_two();
+}
+
+fn to_montgomery<const M: u32>(a: &[ModInt<M>]) -> Vec<Mint<M>> {
+    a.iter().map(|&a| a.into()).collect()
+}
 
-    a.resize(r, zero());
-    number_theoretic_transform(g, &mut a);
-    b.resize(r, zero());
-    number_theoretic_transform(g, &mut b);
+fn from_montgomery<const M: u32>(a: &mut [ModInt<M>], b: Vec<Mint<M>>) {
     for (a, b) in a.iter_mut().zip(b) {
-        *a *= b;
+        *a = b.into();
+    }
+}
+
+// (w^j, w^2j, w^3j) (j < m)
+fn table<const M: u32>(w: Mint<M>, m: usize) -> Vec<[Mint<M>; 3]> {
+    let mut res = Vec::with_capacity(m);
+    let mut p = one::<Mint<M>>();
+    for _ in 0..m {
+        let p2 = p * p;
+        res.push([p, p2, p2 * p]);
+        p *= w;
+    }
+    res
+}
+
+fn bit_reverse<T>(a: &mut [T]) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
     }
-    inverse_number_theoretic_transform(g, &mut a);
-    a.truncate(n + m - 1);
-    a
 }
 
 #[test]
 fn test_number_theoretic_transform() {
-    use crate::other::fp::P;
     use crate::other::rand::{rand_int, random};
 
-    assert_eq!(P, 998244353);
-
-    fn naive(a: &Vec<Fp>, b: &Vec<Fp>) -> Vec<Fp> {
+    fn naive<const M: u32>(a: &Vec<ModInt<M>>, b: &Vec<ModInt<M>>) -> Vec<ModInt<M>> {
         use std::cmp::max;
 
         let mut c = vec![zero(); a.len() + b.len()];
@@ -101,15 +298,68 @@ fn test_number_theoretic_transform() {
         c
     }
 
-    let q = 100;
-    let n_max = 100;
-    for _ in 0..q {
-        let n = rand_int(0..n_max);
-        let m = rand_int(0..n_max);
+    fn test<const M: u32>() {
+        let q = 100;
+        let n_max = 100;
+        for _ in 0..q {
+            let n = rand_int(0..n_max);
+            let m = rand_int(0..n_max);
 
-        let a: Vec<Fp> = (0..n).map(|_| random()).collect();
-        let b: Vec<Fp> = (0..m).map(|_| random()).collect();
+            let a: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+            let b: Vec<ModInt<M>> = (0..m).map(|_| random()).collect();
 
-        assert_eq!(naive(&a, &b), fp_convolution(Fp(3), a, b));
+            assert_eq!(naive(&a, &b), fp_convolution(a, b));
+        }
     }
+
+    test::<998244353>();
+    test::<754974721>();
+    test::<167772161>();
+    test::<469762049>();
+
+    fn test_plan<const M: u32>() {
+        let mut plan = NttPlan::<M>::new();
+        for lg in 0..12 {
+            let n = 1 << lg;
+            let a: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+            let mut b = a.clone();
+            plan.forward(&mut b);
+            if n <= 64 {
+                let r = ModInt::primitive_root().pow(((M - 1) / n as u32).into());
+                let c: Vec<ModInt<M>> = (0..n)
+                    .map(|i| (0..n).map(|k| a[k] * r.pow((i * k) as u64)).sum())
+                    .collect();
+                assert_eq!(b, c);
+            }
+            plan.inverse(&mut b);
+            assert_eq!(b, a);
+        }
+        for _ in 0..30 {
+            let n = rand_int(0..300);
+            let m = rand_int(0..300);
+            let a: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+            let b: Vec<ModInt<M>> = (0..m).map(|_| random()).collect();
+            assert_eq!(plan.convolution(&a, &b), naive(&a, &b));
+        }
+    }
+
+    test_plan::<998244353>();
+    test_plan::<754974721>();
+    test_plan::<167772161>();
+    test_plan::<469762049>();
+
+    let mut plan = NttPlan::<998244353>::new();
+    let a: Vec<ModInt<998244353>> = (0..1 << 10).map(|_| random()).collect();
+    let mut b = a.clone();
+    plan.forward(&mut b);
+    let mut c: Vec<Mint<998244353>> = a.iter().map(|&a| a.into()).collect();
+    plan.forward_montgomery(&mut c);
+    assert!(c.iter().zip(&b).all(|(&c, &b)| ModInt::from(c) == b));
+    plan.inverse_montgomery(&mut c);
+    assert!(c.iter().zip(&a).all(|(&c, &a)| ModInt::from(c) == a));
+
+    // f が panic しても NttPlan はキャッシュに戻る
+    let r = std::panic::catch_unwind(|| with_plan(|_: &mut NttPlan<998244353>| panic!()));
+    assert!(r.is_err());
+    assert!(PLANS.with(|p| p.borrow().contains_key(&998244353)));
 }
@@ -35,31 +35,34 @@ n と d がとても小さい場合はこの実装で十分だろう。
 
 use crate::matrix;
 use crate::other::algebraic::{One, Zero};
+use crate::other::fp::ModInt;
 use crate::other::fp_utils::FpUtils;
 use crate::other::matrix::Matrix;
-use crate::other::Fp;
 use crate::other::Polynomial;
 
-pub fn polynomial_matrix_prod(a: &Matrix<Polynomial<Fp>>, m: u64) -> Matrix<Fp> {
+pub fn polynomial_matrix_prod<const M: u32>(
+    a: &Matrix<Polynomial<ModInt<M>>>,
+    m: u64,
+) -> Matrix<ModInt<M>> {
     assert_eq!(a.row_count(), a.col_count());
     let n: usize = a.row_count();
     assert!(n >= 1);
     let d: u64 = a.inner().map(|p| p.degree().unwrap_or(0)).max().unwrap() as u64;
     let b: u64 = (0..).find(|&b| (d * b + 1) * b > m).unwrap() - 1;
 
-    let get = |i: u64| a.clone().map(|p| p.evaluate(&Fp::from(i)));
+    let get = |i: u64| a.clone().map(|p| p.evaluate(&ModInt::from(i)));
     let naive = |r: std::ops::Range<u64>| r.map(get).fold(Matrix::identity(n), |b, a| b * a);
 
     let fp_ut = FpUtils::new((d * b + 1) as usize);
 
-    let interpolate = |part: &[Matrix<Fp>], t: u64, len: usize| -> Vec<Matrix<Fp>> {
-        let mut ret = vec![matrix![Fp::zero(); n; n]; len];
-        let t = Fp::from(t) / Fp::from(b) - Fp::from(part.len() - 1);
+    let interpolate = |part: &[Matrix<ModInt<M>>], t: u64, len: usize| -> Vec<Matrix<ModInt<M>>> {
+        let mut ret = vec![matrix![ModInt::zero(); n; n]; len];
+        let t = ModInt::from(t) / ModInt::from(b) - ModInt::from(part.len() - 1);
         let sfact = {
-            let mut sfact = vec![Fp::zero(); part.len() + len];
-            sfact[0] = Fp::one();
+            let mut sfact = vec![ModInt::zero(); part.len() + len];
+            sfact[0] = ModInt::one();
             for i in 0..sfact.len() - 1 {
-                let temp = sfact[i] * (t + Fp::from(i));
+                let temp = sfact[i] * (t + ModInt::from(i));
                 sfact[i + 1] = temp;
             }
             sfact
@@ -67,16 +70,15 @@ pub fn polynomial_matrix_prod(a: &Matrix<Polynomial<Fp>>, m: u64) -> Matrix<Fp>
 
         for ir in 0..n {
             for ic in 0..n {
-                let mut s: Vec<Fp> = part.iter().map(|mat| mat[ir][ic]).collect();
+                let mut s: Vec<ModInt<M>> = part.iter().map(|mat| mat[ir][ic]).collect();
                 for (i, s) in s.iter_mut().enumerate() {
-                    *s *= (-Fp::one()).pow((part.len() - 1 - i) as u64)
+                    *s *= (-ModInt::one()).pow((part.len() - 1 - i) as u64)
                         * (fp_ut.inv_fact(i) * fp_ut.inv_fact(part.len() - 1 - i));
                 }
-                let l: Vec<Fp> = (0..part.len() + len - 1)
-                    .map(|i| Fp::one() / (t + Fp::from(i)))
+                let l: Vec<ModInt<M>> = (0..part.len() + len - 1)
+                    .map(|i| ModInt::one() / (t + ModInt::from(i)))
                     .collect();
-                let r =
-                    crate::algorithm::number_theoretic_transform::fp_convolution(Fp::from(3), s, l);
+                let r = crate::algorithm::number_theoretic_transform::fp_convolution(s, l);
                 for i in 0..len {
                     ret[i][ir][ic] = r[part.len() - 1 + i] * sfact[part.len() + i] / sfact[i];
                 }
@@ -88,37 +90,41 @@ pub fn polynomial_matrix_prod(a: &Matrix<Polynomial<Fp>>, m: u64) -> Matrix<Fp>
 
     use crate::other::recurse::recurse;
 
-    let eval_s = recurse::<u64, Vec<Matrix<Fp>>, _>(|eval_s, w: u64| -> Vec<Matrix<Fp>> {
-        if w == 0 {
-            return vec![Matrix::identity(n)];
-        }
-
-        if w % 2 == 1 {
-            let mut s = eval_s(w - 1);
-            for (i, s) in s.iter_mut().enumerate() {
-                *s = s.clone() * get(b * i as u64 + (w - 1));
+    let eval_s =
+        recurse::<u64, Vec<Matrix<ModInt<M>>>, _>(|eval_s, w: u64| -> Vec<Matrix<ModInt<M>>> {
+            if w == 0 {
+                return vec![Matrix::identity(n)];
             }
-            s.extend((d * (w - 1) + 1..d * w + 1).map(|i| naive(b * i..b * i + w)));
-            s
-        } else {
-            let mut s = eval_s(w / 2);
-            let x = interpolate(&s, b * (d * (w / 2) + 1), (d * (w / 2)) as usize);
-            let y = interpolate(&s, w / 2, (d * w) as usize + 1);
-            s.extend(x);
-            for (s, y) in s.iter_mut().zip(y) {
-                *s = s.clone() * y;
+
+            if w % 2 == 1 {
+                let mut s = eval_s(w - 1);
+                for (i, s) in s.iter_mut().enumerate() {
+                    *s = s.clone() * get(b * i as u64 + (w - 1));
+                }
+                s.extend((d * (w - 1) + 1..d * w + 1).map(|i| naive(b * i..b * i + w)));
+                s
+            } else {
+                let mut s = eval_s(w / 2);
+                let x = interpolate(&s, b * (d * (w / 2) + 1), (d * (w / 2)) as usize);
+                let y = interpolate(&s, w / 2, (d * w) as usize + 1);
+                s.extend(x);
+                for (s, y) in s.iter_mut().zip(y) {
+                    *s = s.clone() * y;
+                }
+                s
             }
-            s
-        }
-    });
+        });
 
     let s = eval_s(b);
-    s.into_iter().fold(Matrix::identity(n), |b, a| b * a) * naive((d * b + 1) * b..m)
+    s.into_iter()
+        .fold(Matrix::<ModInt<M>>::identity(n), |b, a| b * a)
+        * naive((d * b + 1) * b..m)
 }
 
 #[test]
 fn test_polynomial_matrix_prod() {
     use crate::other::rand::rand_int;
+    use crate::other::Fp;
     {
         // general
         fn test(n: usize, d: usize, m: u64, u: u32) {
@@ -126,7 +132,7 @@ fn test_polynomial_matrix_prod() {
                 let mut a = matrix![Polynomial::new(); n; n];
                 for i in 0..n {
                     for j in 0..n {
-                        a[i][j].coef = (0..d).map(|_| Fp(rand_int(0..u))).collect();
+                        a[i][j].coef = (0..d).map(|_| Fp::new(rand_int(0..u))).collect();
                     }
                 }
                 a
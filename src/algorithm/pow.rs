@@ -23,9 +23,9 @@ fn test_pow() {
     let q = 1000;
     let exp_max = 1000;
     for _ in 0..q {
-        let base = Fp(rand_int(0..P));
+        let base = Fp::new(rand_int(0..P));
         let exp = rand_int(0..exp_max);
-        let mut ans = Fp(0);
+        let mut ans = Fp::new(0);
         for _ in 0..exp {
             ans += base;
         }
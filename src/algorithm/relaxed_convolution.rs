@@ -0,0 +1,143 @@
+/*
+
+Reference
+
+[1] van der Hoeven, J. (1997).
+    Lazy multiplication of formal power series.
+    In Proceedings of the 1997 international symposium on Symbolic and algebraic computation
+    (pp. 17-20).
+
+
+Description
+
+a, b: F_M の列
+
+push(a_i, b_i): a_i, b_i を追加し、c_i := Σ_{j+k=i} a_j b_k を返す。
+c_i は a_0, ..., a_i, b_0, ..., b_i のみに依存するので、
+f_{i+1} = Σ_{j<=i} f_j g_{i+1-j} のような自身を参照する漸化式を
+push(f_i, g_{i+1}) の返り値から f_{i+1} を得ることで計算できる。
+
+時間計算量: 償却 O(log(n)^2) / push
+
+(j, k) 平面を [s, 2s) × [ts, (t+1)s) (t >= 1) と [ts, (t+1)s) × [s, 2s) (t >= 2) の
+正方形に分割する (s は 2 冪)。
+各正方形は必要な項が揃った時点で NTT により一度に計算し、
+結果を c の先の項に足しておく。
+M は NTT に適した素数である必要がある。
+
+*/
+
+use crate::algorithm::number_theoretic_transform::NttPlan;
+use crate::other::algebraic::zero;
+use crate::other::fp::ModInt;
+
+const NAIVE_THRESHOLD: usize = 32;
+
+pub struct RelaxedConvolution<const M: u32> {
+    a: Vec<ModInt<M>>,
+    b: Vec<ModInt<M>>,
+    c: Vec<ModInt<M>>,
+    plan: NttPlan<M>,
+}
+
+impl<const M: u32> RelaxedConvolution<M> {
+    pub fn new() -> Self {
+        Self {
+            a: Vec::new(),
+            b: Vec::new(),
+            c: Vec::new(),
+            plan: NttPlan::new(),
+        }
+    }
+
+    pub fn push(&mut self, a: ModInt<M>, b: ModInt<M>) -> ModInt<M> {
+        let i = self.a.len();
+        self.a.push(a);
+        self.b.push(b);
+        if self.c.len() <= i {
+            self.c.resize(i + 1, zero());
+        }
+        if i == 0 {
+            self.c[0] += a * b;
+        } else {
+            let v = a * self.b[0] + self.a[0] * b;
+            self.c[i] += v;
+        }
+
+        let mut s = 1;
+        while (i + 1).is_multiple_of(s) && i + 1 >= 2 * s {
+            let t = (i + 1) / s - 1;
+            self.add_block(s, t * s, s);
+            if t >= 2 {
+                self.add_block(t * s, s, s);
+            }
+            s *= 2;
+        }
+        self.c[i]
+    }
+
+    // c += a[p..p+s] * b[q..q+s] (x^(p+q))
+    fn add_block(&mut self, p: usize, q: usize, s: usize) {
+        let a = &self.a[p..p + s];
+        let b = &self.b[q..q + s];
+        let d = if s <= NAIVE_THRESHOLD {
+            let mut d = vec![zero::<ModInt<M>>(); 2 * s - 1];
+            for (j, &a) in a.iter().enumerate() {
+                for (d, &b) in d[j..].iter_mut().zip(b) {
+                    *d += a * b;
+                }
+            }
+            d
+        } else {
+            self.plan.convolution(a, b)
+        };
+        if self.c.len() < p + q + d.len() {
+            self.c.resize(p + q + d.len(), zero());
+        }
+        for (c, d) in self.c[p + q..].iter_mut().zip(d) {
+            *c += d;
+        }
+    }
+}
+
+impl<const M: u32> Default for RelaxedConvolution<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn test_relaxed_convolution() {
+    use crate::other::rand::{rand_int, random};
+
+    fn test<const M: u32>(n: usize) {
+        let a: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+        let b: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+        let mut rc = RelaxedConvolution::new();
+        for i in 0..n {
+            let naive: ModInt<M> = (0..=i).map(|j| a[j] * b[i - j]).sum();
+            assert_eq!(rc.push(a[i], b[i]), naive);
+        }
+
+        // f_0 = 1, f_{i+1} = Σ_{j<=i} f_j g_{i+1-j}
+        let g: Vec<ModInt<M>> = (0..n + 1).map(|_| random()).collect();
+        let mut naive = vec![ModInt::new(1)];
+        for i in 0..n {
+            let v = (0..=i).map(|j| naive[j] * g[i + 1 - j]).sum();
+            naive.push(v);
+        }
+        let mut rc = RelaxedConvolution::new();
+        let mut f = vec![ModInt::new(1)];
+        for i in 0..n {
+            let v = rc.push(f[i], g[i + 1]);
+            f.push(v);
+        }
+        assert_eq!(f, naive);
+    }
+
+    for _ in 0..100 {
+        test::<998244353>(rand_int(0..100));
+    }
+    test::<998244353>(1000);
+    test::<469762049>(777);
+}
@@ -1,220 +1,474 @@
-/*
-
-Reference
-
-[1] Cantor, D. G., & Kaltofen, E. (1987).
-    Fast multiplication of polynomials over arbitrary rings. Acta Inf, 28.
-
-*/
-
-use crate::other::algebraic::Ring;
-use crate::other::itertools::zip;
-use crate::other::Polynomial;
-
-use std::fmt::Debug;
-
-pub fn schoenhage_strassen<R>(a: &[R], b: &[R]) -> Vec<R>
-where
-    R: Ring + Clone + Debug,
-{
-    if a.is_empty() || b.is_empty() {
-        return vec![];
-    }
-    let (k2, c2) = schoenhage_strassen_2fold(a, b);
-    let (k3, c3) = schoenhage_strassen_3fold(a, b);
-    let (x, y) = {
-        use crate::other::pow::pow_signed;
-        let (x, y, g) = crate::other::extgcd::extgcd(2i64.pow(k2), 3i64.pow(k3));
-        assert_eq!(g, 1);
-        (pow_signed(R::one(), x), pow_signed(R::one(), y))
-    };
-    zip(c2, c3)
-        .map(|(c2, c3)| x.clone() * c2 + y.clone() * c3)
-        .collect()
-}
-
-pub use ss_2fold::schoenhage_strassen_2fold;
-
-mod ss_2fold {
-    use super::*;
-
-    pub fn schoenhage_strassen_2fold<R>(a: &[R], b: &[R]) -> (u32, Vec<R>)
-    where
-        R: Ring + Clone + Debug,
-    {
-        if a.is_empty() || b.is_empty() {
-            return (0, vec![]);
-        }
-        let m = a.len() + b.len() - 1;
-        let k = m.next_power_of_two().trailing_zeros();
-        let n = 2usize.pow(k);
-        let a = resized(a, n);
-        let b = resized(b, n);
-        let mut c = schoenhage_strassen_2fold_sub(k, a, b).coef;
-        fn calc_exp(mut k: u32) -> u32 {
-            let mut ret: u32 = 0;
-            while k > 2 {
-                let l = (k + 1) / 2;
-                ret += l;
-                k = k - l + 1;
-            }
-            ret
-        }
-        c.truncate(m);
-        (calc_exp(k), c)
-    }
-
-    fn schoenhage_strassen_2fold_sub<R>(k: u32, a: Polynomial<R>, b: Polynomial<R>) -> Polynomial<R>
-    where
-        R: Ring + Clone + Debug,
-    {
-        let n = 2usize.pow(k);
-
-        if k <= 2 {
-            return negcyc(a * b, n);
-        }
-
-        let l = (k + 1) / 2;
-        let lp = 2usize.pow(l);
-        let m = k - l;
-        let mp = 2usize.pow(m);
-
-        let fft = |a: &mut [Polynomial<R>]| {
-            for w in (0..l).rev().map(|x| 2usize.pow(x)) {
-                let root: usize = (4 * mp) / (2 * w);
-                for a in a.chunks_exact_mut(2 * w) {
-                    let (x, y) = a.split_at_mut(w);
-                    for (p, (x, y)) in zip(x, y).enumerate() {
-                        let nx = x.clone() + y.clone();
-                        let ny = negcyc((x.clone() - y.clone()) << (p * root), 2 * mp);
-                        *x = nx;
-                        *y = ny;
-                    }
-                }
-            }
-        };
-
-        let ifft = |a: &mut [Polynomial<R>]| {
-            for w in (0..l).map(|x| 2usize.pow(x)) {
-                let root: usize = (4 * mp) / (2 * w);
-                for a in a.chunks_exact_mut(2 * w) {
-                    let (x, y) = a.split_at_mut(w);
-                    for (p, (x, y)) in zip(x, y).enumerate() {
-                        let py = shr(y.clone(), p * root);
-                        let nx = x.clone() + py.clone();
-                        *y = x.clone() - py;
-                        *x = nx;
-                    }
-                }
-            }
-        };
-
-        let weight = 2 * mp / lp;
-
-        let extend_and_fft = |a: &[R]| -> Vec<Polynomial<R>> {
-            let mut ah: Vec<Polynomial<R>> = a
-                .chunks_exact(mp)
-                .enumerate()
-                .map(|(i, a)| negcyc(resized(a, 2 * mp) << (i * weight), 2 * mp))
-                .collect();
-            fft(&mut ah);
-            ah
-        };
-
-        let ah = extend_and_fft(&a.coef);
-        let bh = extend_and_fft(&b.coef);
-        let mut ch: Vec<Polynomial<R>> = zip(ah, bh)
-            .map(|(a, b)| schoenhage_strassen_2fold_sub(m + 1, a, b))
-            .collect();
-        ifft(&mut ch);
-        for (i, c) in ch.iter_mut().enumerate() {
-            *c = shr(c.clone(), i * weight);
-        }
-        let mut c = vec![R::zero(); n];
-        for (i, mut ch) in ch.into_iter().enumerate() {
-            if i + 1 == lp {
-                let w = ch.coef.split_off(mp);
-                for (c, ch) in zip(&mut c[mp * i..], ch) {
-                    *c += ch;
-                }
-                for (c, w) in zip(&mut c, w) {
-                    *c -= w;
-                }
-            } else {
-                for (c, ch) in zip(&mut c[mp * i..mp * (i + 2)], ch) {
-                    *c += ch;
-                }
-            }
-        }
-        c.into()
-    }
-
-    fn shr<R>(mut p: Polynomial<R>, n: usize) -> Polynomial<R>
-    where
-        R: Ring + Debug,
-    {
-        let mut w = p.coef.split_off(n);
-        w.extend((-p).coef);
-        w.into()
-    }
-
-    fn negcyc<R>(mut p: Polynomial<R>, n: usize) -> Polynomial<R>
-    where
-        R: Ring,
-    {
-        let w = p.coef.split_off(n);
-        for (c, w) in zip(&mut p, w) {
-            *c -= w;
-        }
-        p
-    }
-}
-
-pub fn schoenhage_strassen_3fold<R>(_a: &[R], _b: &[R]) -> (u32, Vec<R>)
-where
-    R: Ring,
-{
-    unimplemented!()
-}
-
-fn resized<R>(a: &[R], n: usize) -> Polynomial<R>
-where
-    R: Ring + Clone,
-{
-    a.iter()
-        .cloned()
-        .chain(std::iter::once(R::zero()).cycle())
-        .take(n)
-        .collect()
-}
-
-#[test]
-fn test_schoenhage_strassen_2fold() {
-    use crate::other::rand::{rand_int, random};
-    use crate::other::Fp;
-    use crate::other::Polynomial;
-
-    fn check(a: Polynomial<Fp>, b: Polynomial<Fp>) {
-        let (k, c) = schoenhage_strassen_2fold(&a.coef, &b.coef);
-        let c = Polynomial { coef: c };
-        let r: &Fp = &random();
-        assert_eq!(
-            a.evaluate(r) * b.evaluate(r) * Fp::from(2).pow(k.into()),
-            c.evaluate(r)
-        );
-    }
-
-    fn test(n: usize, m: usize) {
-        let a: Polynomial<Fp> = (0..n).map(|_| random()).collect();
-        let b: Polynomial<Fp> = (0..m).map(|_| random()).collect();
-        check(a, b);
-    }
-
-    for _ in 0..1000 {
-        test(rand_int(0..10), rand_int(0..10));
-    }
-
-    for _ in 0..3 {
-        test(rand_int(0..10000), rand_int(0..10000));
-    }
-}
+/*
+
+Reference
+
+[1] Cantor, D. G., & Kaltofen, E. (1987).
+    Fast multiplication of polynomials over arbitrary rings. Acta Inf, 28.
+
+
+Description
+
+R: 環
+a, b: R 上の多項式
+n: 次数の和
+
+schoenhage_strassen(a, b): a b を計算する。
+schoenhage_strassen_2fold(a, b): 2^k a b を計算し、(k, 2^k a b) を返す。
+schoenhage_strassen_3fold(a, b): 3^k a b を計算し、(k, 3^k a b) を返す。
+
+時間計算量: O(n log(n) log(log(n))) 回の R の演算
+
+2fold は R[x] / (x^(2^k) + 1) 上で 2 冪の DFT を、
+3fold は R[x] / (x^(2 3^k) + x^(3^k) + 1) 上で 3 冪の DFT を再帰的に用いる。
+いずれも逆変換で 2 や 3 による除算が必要になるが、これを行わずに倍数を返す。
+2^i と 3^j は互いに素であるから、拡張ユークリッドの互除法で得られる係数で
+結果を組み合わせれば、任意の環上で a b が得られる。
+
+*/
+
+use crate::other::algebraic::Ring;
+use crate::other::itertools::zip;
+use crate::other::Polynomial;
+
+pub fn schoenhage_strassen<R>(a: &[R], b: &[R]) -> Vec<R>
+where
+    R: Ring + Clone,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let (k2, c2) = schoenhage_strassen_2fold(a, b);
+    let (k3, c3) = schoenhage_strassen_3fold(a, b);
+    let (x, y) = {
+        use crate::other::pow::pow_signed;
+        let (x, y, g) = crate::other::extgcd::extgcd(2i64.pow(k2), 3i64.pow(k3));
+        assert_eq!(g, 1);
+        (pow_signed(R::one(), x), pow_signed(R::one(), y))
+    };
+    zip(c2, c3)
+        .map(|(c2, c3)| x.clone() * c2 + y.clone() * c3)
+        .collect()
+}
+
+pub use ss_2fold::schoenhage_strassen_2fold;
+
+mod ss_2fold {
+    use super::*;
+
+    pub fn schoenhage_strassen_2fold<R>(a: &[R], b: &[R]) -> (u32, Vec<R>)
+    where
+        R: Ring + Clone,
+    {
+        if a.is_empty() || b.is_empty() {
+            return (0, vec![]);
+        }
+        let m = a.len() + b.len() - 1;
+        let k = m.next_power_of_two().trailing_zeros();
+        let n = 2usize.pow(k);
+        let a = resized(a, n);
+        let b = resized(b, n);
+        let mut c = schoenhage_strassen_2fold_sub(k, a, b).coef;
+        fn calc_exp(mut k: u32) -> u32 {
+            let mut ret: u32 = 0;
+            while k > 2 {
+                let l = k.div_ceil(2);
+                ret += l;
+                k = k - l + 1;
+            }
+            ret
+        }
+        c.truncate(m);
+        (calc_exp(k), c)
+    }
+
+    fn schoenhage_strassen_2fold_sub<R>(k: u32, a: Polynomial<R>, b: Polynomial<R>) -> Polynomial<R>
+    where
+        R: Ring + Clone,
+    {
+        let n = 2usize.pow(k);
+
+        if k <= 2 {
+            return negcyc(naive_mul(&a, &b), n);
+        }
+
+        let l = k.div_ceil(2);
+        let lp = 2usize.pow(l);
+        let m = k - l;
+        let mp = 2usize.pow(m);
+
+        let fft = |a: &mut [Polynomial<R>]| {
+            for w in (0..l).rev().map(|x| 2usize.pow(x)) {
+                let root: usize = (4 * mp) / (2 * w);
+                for a in a.chunks_exact_mut(2 * w) {
+                    let (x, y) = a.split_at_mut(w);
+                    for (p, (x, y)) in zip(x, y).enumerate() {
+                        let nx = x.clone() + y.clone();
+                        let ny = negcyc((x.clone() - y.clone()) << (p * root), 2 * mp);
+                        *x = nx;
+                        *y = ny;
+                    }
+                }
+            }
+        };
+
+        let ifft = |a: &mut [Polynomial<R>]| {
+            for w in (0..l).map(|x| 2usize.pow(x)) {
+                let root: usize = (4 * mp) / (2 * w);
+                for a in a.chunks_exact_mut(2 * w) {
+                    let (x, y) = a.split_at_mut(w);
+                    for (p, (x, y)) in zip(x, y).enumerate() {
+                        let py = shr(y.clone(), p * root);
+                        let nx = x.clone() + py.clone();
+                        *y = x.clone() - py;
+                        *x = nx;
+                    }
+                }
+            }
+        };
+
+        let weight = 2 * mp / lp;
+
+        let extend_and_fft = |a: &[R]| -> Vec<Polynomial<R>> {
+            let mut ah: Vec<Polynomial<R>> = a
+                .chunks_exact(mp)
+                .enumerate()
+                .map(|(i, a)| negcyc(resized(a, 2 * mp) << (i * weight), 2 * mp))
+                .collect();
+            fft(&mut ah);
+            ah
+        };
+
+        let ah = extend_and_fft(&a.coef);
+        let bh = extend_and_fft(&b.coef);
+        let mut ch: Vec<Polynomial<R>> = zip(ah, bh)
+            .map(|(a, b)| schoenhage_strassen_2fold_sub(m + 1, a, b))
+            .collect();
+        ifft(&mut ch);
+        for (i, c) in ch.iter_mut().enumerate() {
+            *c = shr(c.clone(), i * weight);
+        }
+        let mut c = vec![R::zero(); n];
+        for (i, mut ch) in ch.into_iter().enumerate() {
+            if i + 1 == lp {
+                let w = ch.coef.split_off(mp);
+                for (c, ch) in zip(&mut c[mp * i..], ch) {
+                    *c += ch;
+                }
+                for (c, w) in zip(&mut c, w) {
+                    *c -= w;
+                }
+            } else {
+                for (c, ch) in zip(&mut c[mp * i..mp * (i + 2)], ch) {
+                    *c += ch;
+                }
+            }
+        }
+        c.into()
+    }
+
+    fn shr<R>(mut p: Polynomial<R>, n: usize) -> Polynomial<R>
+    where
+        R: Ring,
+    {
+        let mut w = p.coef.split_off(n);
+        w.extend((-p).coef);
+        w.into()
+    }
+
+    fn negcyc<R>(mut p: Polynomial<R>, n: usize) -> Polynomial<R>
+    where
+        R: Ring,
+    {
+        let w = p.coef.split_off(n);
+        for (c, w) in zip(&mut p, w) {
+            *c -= w;
+        }
+        p
+    }
+}
+
+pub use ss_3fold::schoenhage_strassen_3fold;
+
+mod ss_3fold {
+    use super::*;
+
+    pub fn schoenhage_strassen_3fold<R>(a: &[R], b: &[R]) -> (u32, Vec<R>)
+    where
+        R: Ring + Clone,
+    {
+        if a.is_empty() || b.is_empty() {
+            return (0, vec![]);
+        }
+        let m = a.len() + b.len() - 1;
+        let mut k = 0;
+        while 2 * 3usize.pow(k) < m {
+            k += 1;
+        }
+        let n = 2 * 3usize.pow(k);
+        let a = resized(a, n);
+        let b = resized(b, n);
+        let mut c = schoenhage_strassen_3fold_sub(k, a, b).coef;
+        fn calc_exp(mut k: u32) -> u32 {
+            let mut ret: u32 = 0;
+            while k > 2 {
+                let l = k / 2;
+                ret += l + 1;
+                k -= l;
+            }
+            ret
+        }
+        c.truncate(m);
+        (calc_exp(k), c)
+    }
+
+    // R[x] / (x^(2 3^k) + x^(3^k) + 1) での積
+    fn schoenhage_strassen_3fold_sub<R>(k: u32, a: Polynomial<R>, b: Polynomial<R>) -> Polynomial<R>
+    where
+        R: Ring + Clone,
+    {
+        let n = 3usize.pow(k);
+
+        if k <= 2 {
+            return cyclotomic(naive_mul(&a, &b), n);
+        }
+
+        let l = k / 2;
+        let lp = 3usize.pow(l);
+        let m = k - l;
+        let mp = 3usize.pow(m);
+
+        // x は R[x] / (x^(2mp) + x^mp + 1) における 1 の原始 3mp 乗根
+        let order = 3 * mp;
+        let omega = mp;
+        let zeta = mp / lp;
+
+        let fft = |a: &mut [Polynomial<R>]| {
+            for w in (0..l).rev().map(|x| 3usize.pow(x)) {
+                let root = order / (3 * w);
+                for a in a.chunks_exact_mut(3 * w) {
+                    let (x, yz) = a.split_at_mut(w);
+                    let (y, z) = yz.split_at_mut(w);
+                    for (p, (x, (y, z))) in zip(x, zip(y, z)).enumerate() {
+                        let nx = x.clone() + y.clone() + z.clone();
+                        let ny =
+                            x.clone() + rot(y.clone(), omega, mp) + rot(z.clone(), 2 * omega, mp);
+                        let nz =
+                            x.clone() + rot(y.clone(), 2 * omega, mp) + rot(z.clone(), omega, mp);
+                        *x = nx;
+                        *y = rot(ny, p * root, mp);
+                        *z = rot(nz, 2 * p * root, mp);
+                    }
+                }
+            }
+        };
+
+        let ifft = |a: &mut [Polynomial<R>]| {
+            for w in (0..l).map(|x| 3usize.pow(x)) {
+                let root = order / (3 * w);
+                for a in a.chunks_exact_mut(3 * w) {
+                    let (x, yz) = a.split_at_mut(w);
+                    let (y, z) = yz.split_at_mut(w);
+                    for (p, (x, (y, z))) in zip(x, zip(y, z)).enumerate() {
+                        let py = rot(y.clone(), order - p * root, mp);
+                        let pz = rot(z.clone(), order - 2 * p * root, mp);
+                        let nx = x.clone() + py.clone() + pz.clone();
+                        *y =
+                            x.clone() + rot(py.clone(), 2 * omega, mp) + rot(pz.clone(), omega, mp);
+                        *z = x.clone() + rot(py, omega, mp) + rot(pz, 2 * omega, mp);
+                        *x = nx;
+                    }
+                }
+            }
+        };
+
+        // y = x^mp とおくと x^(2n) + x^n + 1 = (y^lp - ω)(y^lp - ω^2)
+        // y = ζz, ζ^2 z と置換して z^lp - 1 を法とする巡回畳み込みに帰着する
+        let split_and_fft = |a: &[R]| -> (Vec<Polynomial<R>>, Vec<Polynomial<R>>) {
+            let (lo, hi) = a.split_at(lp * mp);
+            let mut u = Vec::with_capacity(lp);
+            let mut v = Vec::with_capacity(lp);
+            for (i, (lo, hi)) in zip(lo.chunks_exact(mp), hi.chunks_exact(mp)).enumerate() {
+                let lo = resized(lo, 2 * mp);
+                let hi = resized(hi, 2 * mp);
+                u.push(rot(lo.clone() + rot(hi.clone(), omega, mp), i * zeta, mp));
+                v.push(rot(lo + rot(hi, 2 * omega, mp), 2 * i * zeta, mp));
+            }
+            fft(&mut u);
+            fft(&mut v);
+            (u, v)
+        };
+
+        let (au, av) = split_and_fft(&a.coef);
+        let (bu, bv) = split_and_fft(&b.coef);
+        let mut cu: Vec<Polynomial<R>> = zip(au, bu)
+            .map(|(a, b)| schoenhage_strassen_3fold_sub(m, a, b))
+            .collect();
+        let mut cv: Vec<Polynomial<R>> = zip(av, bv)
+            .map(|(a, b)| schoenhage_strassen_3fold_sub(m, a, b))
+            .collect();
+        ifft(&mut cu);
+        ifft(&mut cv);
+
+        let mut c = vec![R::zero(); 2 * n + mp];
+        for (i, (u, v)) in zip(cu, cv).enumerate() {
+            let u = rot(u, order - i * zeta, mp);
+            let v = rot(v, order - 2 * i * zeta, mp);
+            // (ω - ω^2)^2 = -3 を用いて y^lp の係数の 3 倍を復元する
+            let d = v - u.clone();
+            let hi = rot(d.clone(), omega, mp) - rot(d, 2 * omega, mp);
+            let lo = u.clone() + u.clone() + u - rot(hi.clone(), omega, mp);
+            for (c, lo) in zip(&mut c[mp * i..], lo) {
+                *c += lo;
+            }
+            for (c, hi) in zip(&mut c[mp * (i + lp)..], hi) {
+                *c += hi;
+            }
+        }
+        cyclotomic(c.into(), n)
+    }
+
+    // x^t を掛ける
+    fn rot<R>(p: Polynomial<R>, t: usize, n: usize) -> Polynomial<R>
+    where
+        R: Ring + Clone,
+    {
+        let mut c = vec![R::zero(); 3 * n];
+        for (i, p) in p.into_iter().enumerate() {
+            c[(i + t) % (3 * n)] += p;
+        }
+        cyclotomic(c.into(), n)
+    }
+
+    // x^(2n) + x^n + 1 で割った余り
+    fn cyclotomic<R>(mut p: Polynomial<R>, n: usize) -> Polynomial<R>
+    where
+        R: Ring + Clone,
+    {
+        for i in (2 * n..p.coef.len()).rev() {
+            let c = p.coef[i].clone();
+            p.coef[i - n] -= c.clone();
+            p.coef[i - 2 * n] -= c;
+        }
+        p.coef.resize(2 * n, R::zero());
+        p
+    }
+}
+
+// 再帰の底で用いる愚直な積
+fn naive_mul<R>(a: &Polynomial<R>, b: &Polynomial<R>) -> Polynomial<R>
+where
+    R: Ring + Clone,
+{
+    let mut c = vec![R::zero(); a.coef.len() + b.coef.len() - 1];
+    for (i, a) in a.coef.iter().enumerate() {
+        for (c, b) in c[i..].iter_mut().zip(&b.coef) {
+            *c += a.clone() * b.clone();
+        }
+    }
+    c.into()
+}
+
+fn resized<R>(a: &[R], n: usize) -> Polynomial<R>
+where
+    R: Ring + Clone,
+{
+    a.iter()
+        .cloned()
+        .chain(std::iter::once(R::zero()).cycle())
+        .take(n)
+        .collect()
+}
+
+#[test]
+fn test_schoenhage_strassen_2fold() {
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Fp;
+    use crate::other::Polynomial;
+
+    fn check(a: Polynomial<Fp>, b: Polynomial<Fp>) {
+        let (k, c) = schoenhage_strassen_2fold(&a.coef, &b.coef);
+        let c = Polynomial { coef: c };
+        let r: &Fp = &random();
+        assert_eq!(
+            a.evaluate(r) * b.evaluate(r) * Fp::from(2).pow(k.into()),
+            c.evaluate(r)
+        );
+    }
+
+    fn test(n: usize, m: usize) {
+        let a: Polynomial<Fp> = (0..n).map(|_| random()).collect();
+        let b: Polynomial<Fp> = (0..m).map(|_| random()).collect();
+        check(a, b);
+    }
+
+    for _ in 0..1000 {
+        test(rand_int(0..10), rand_int(0..10));
+    }
+
+    for _ in 0..3 {
+        test(rand_int(0..10000), rand_int(0..10000));
+    }
+}
+
+#[test]
+fn test_schoenhage_strassen_3fold() {
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Fp;
+    use crate::other::Polynomial;
+
+    fn check(a: Polynomial<Fp>, b: Polynomial<Fp>) {
+        let (k, c) = schoenhage_strassen_3fold(&a.coef, &b.coef);
+        let c = Polynomial { coef: c };
+        let r: &Fp = &random();
+        assert_eq!(
+            a.evaluate(r) * b.evaluate(r) * Fp::from(3).pow(k.into()),
+            c.evaluate(r)
+        );
+    }
+
+    fn test(n: usize, m: usize) {
+        let a: Polynomial<Fp> = (0..n).map(|_| random()).collect();
+        let b: Polynomial<Fp> = (0..m).map(|_| random()).collect();
+        check(a, b);
+    }
+
+    for _ in 0..1000 {
+        test(rand_int(0..10), rand_int(0..10));
+    }
+
+    for _ in 0..3 {
+        test(rand_int(0..10000), rand_int(0..10000));
+    }
+}
+
+#[test]
+fn test_schoenhage_strassen() {
+    use crate::other::fp::ModInt;
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Polynomial;
+    use std::fmt::Debug;
+    use std::num::Wrapping;
+
+    fn test<R>(n: usize, m: usize, gen: impl Fn() -> R)
+    where
+        R: Ring + Clone + Debug + Eq,
+    {
+        let a: Vec<R> = (0..n).map(|_| gen()).collect();
+        let b: Vec<R> = (0..m).map(|_| gen()).collect();
+        let c = schoenhage_strassen(&a, &b);
+        if n == 0 || m == 0 {
+            assert!(c.is_empty());
+        } else {
+            assert_eq!(c, (Polynomial::from(a) * Polynomial::from(b)).coef);
+        }
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..100);
+        let m = rand_int(0..100);
+        test(n, m, || Wrapping(random::<i64>()));
+        test(n, m, random::<ModInt<{ 1 << 20 }>>);
+    }
+    test(1000, 1000, || Wrapping(random::<i64>()));
+    test(1000, 1000, random::<ModInt<{ 1 << 20 }>>);
+}
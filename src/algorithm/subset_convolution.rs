@@ -1,7 +1,36 @@
+/*
+
+Description
+
+T: 環 (subset_exp, subset_log, subset_composition では可換環)
+a, b: 長さ 2^n の T の列 (集合冪級数)
+f: T 上の多項式
+
+subset_convolution(a, b): c_S := Σ_{T∪U=S, T∩U=∅} a_T b_U
+subset_exp(a): Σ_k a^k / k! 、ただし a_∅ = 0
+subset_log(b): exp(a) = b を満たす a 、ただし b_∅ = 1
+subset_composition(f, a): f(a) = Σ_k f_k a^k 、ただし a_∅ = 0
+ここで積は subset_convolution である。
+
+時間計算量: Θ(2^n n^2) 回の T の演算
+
+各集合の要素数を次数とする多項式を考え、次数ごとに subset_zeta を行い、
+各 S について多項式の積をとってから subset_mobius で戻す。
+多項式は次数ごとの長さ 2^n の配列として持つ。
+
+exp, log, composition は最大の要素 k を含む部分を、
+k 未満の要素のみからなる部分との subset_convolution で順に求める。
+f(a) の k を含む部分は a の k を含む部分と f'(a) の k を含まない部分の積なので、
+導関数 f^(j)(a) をまとめて持って k を増やしていく。
+いずれも除算を用いないので、一般の可換環で計算できる。
+
+*/
+
 use crate::algorithm::zeta_transform;
-use crate::other::algebraic::Ring;
+use crate::other::algebraic::{one, zero, CommutativeRing, Ring};
+use crate::other::fp::ModInt;
+use crate::other::montgomery_mod_int::MontgomeryModInt;
 use crate::other::Polynomial;
-use itertools::{enumerate, zip};
 
 pub fn subset_convolution<T>(a: Vec<T>, b: Vec<T>) -> Vec<T>
 where
@@ -11,25 +40,152 @@ where
     assert!(n.is_power_of_two());
     assert_eq!(b.len(), n);
 
-    let ranked_zeta = |a: Vec<_>| {
-        let mut a_ext = enumerate(a)
-            .map(|(i, a)| Polynomial::from(vec![a]) << i.count_ones() as usize)
-            .collect();
-        zeta_transform::subset_zeta(&mut a_ext);
-        a_ext
-    };
+    ranked_mobius(ranked_mul(&ranked_zeta(&a), &ranked_zeta(&b)))
+}
+
+pub fn fp_subset_convolution<const M: u32>(a: Vec<ModInt<M>>, b: Vec<ModInt<M>>) -> Vec<ModInt<M>> {
+    let to_montgomery = |a: Vec<ModInt<M>>| a.into_iter().map(MontgomeryModInt::from).collect();
+    subset_convolution(to_montgomery(a), to_montgomery(b))
+        .into_iter()
+        .map(ModInt::from)
+        .collect()
+}
+
+pub fn subset_exp<T>(a: Vec<T>) -> Vec<T>
+where
+    T: CommutativeRing + Clone,
+{
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    assert!(a[0].is_zero());
+    let mut e = Vec::with_capacity(n);
+    e.push(one());
+    for k in (0..n.trailing_zeros()).map(|i| 1 << i) {
+        let high = subset_convolution(a[k..2 * k].to_vec(), e.clone());
+        e.extend(high);
+    }
+    e
+}
 
-    let a_ext = ranked_zeta(a);
-    let b_ext = ranked_zeta(b);
+pub fn subset_log<T>(b: Vec<T>) -> Vec<T>
+where
+    T: CommutativeRing + Clone,
+{
+    let n = b.len();
+    assert!(n.is_power_of_two());
+    assert!((b[0].clone() - one()).is_zero());
+    let mut a = Vec::with_capacity(n);
+    a.push(zero());
+    for k in (0..n.trailing_zeros()).map(|i| 1 << i) {
+        let inv = ranked_inv(ranked_zeta(&b[..k]));
+        let high = ranked_mobius(ranked_mul(&ranked_zeta(&b[k..2 * k]), &inv));
+        a.extend(high);
+    }
+    a
+}
 
-    let mut c_ext: Vec<_> = zip(a_ext, b_ext).map(|(a, b)| a * b).collect();
-    zeta_transform::subset_mobius(&mut c_ext);
+pub fn subset_composition<T>(f: &Polynomial<T>, a: Vec<T>) -> Vec<T>
+where
+    T: CommutativeRing + Clone,
+{
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    assert!(a[0].is_zero());
+    let lg = n.trailing_zeros() as usize;
+
+    // g[j] = f^(j)(a) の 2^k 未満の部分、k = 0 では f^(j)(0) = j! f_j
+    let mut g: Vec<Vec<T>> = Vec::with_capacity(lg + 1);
+    let mut fact: T = one();
+    let mut i: T = zero();
+    for j in 0..=lg {
+        if j != 0 {
+            i += one();
+            fact *= i.clone();
+        }
+        let c = f.coef.get(j).cloned().unwrap_or_else(zero);
+        g.push(vec![fact.clone() * c]);
+    }
+
+    for k in (0..lg).map(|i| 1 << i) {
+        let high = ranked_zeta(&a[k..2 * k]);
+        for j in 0..g.len() - 1 {
+            let h = ranked_mobius(ranked_mul(&high, &ranked_zeta(&g[j + 1])));
+            g[j].extend(h);
+        }
+        g.pop();
+    }
+    g.swap_remove(0)
+}
 
-    enumerate(c_ext)
-        .map(|(i, c)| c[i.count_ones() as usize].clone())
+// res[r][S] = Σ_{T⊆S, |T|=r} a_T
+fn ranked_zeta<T>(a: &[T]) -> Vec<Vec<T>>
+where
+    T: Ring + Clone,
+{
+    let n = a.len();
+    let lg = n.trailing_zeros() as usize;
+    let mut res = vec![vec![zero::<T>(); n]; lg + 1];
+    for (s, a) in a.iter().enumerate() {
+        res[s.count_ones() as usize][s] = a.clone();
+    }
+    for r in &mut res {
+        zeta_transform::subset_zeta(r);
+    }
+    res
+}
+
+fn ranked_mobius<T>(mut a: Vec<Vec<T>>) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    for r in &mut a {
+        zeta_transform::subset_mobius(r);
+    }
+    let n = a[0].len();
+    (0..n)
+        .map(|s| std::mem::replace(&mut a[s.count_ones() as usize][s], zero()))
         .collect()
 }
 
+// 各 S について、次数 lg 以下で打ち切った多項式の積
+fn ranked_mul<T>(a: &[Vec<T>], b: &[Vec<T>]) -> Vec<Vec<T>>
+where
+    T: Ring + Clone,
+{
+    let lg = a.len() - 1;
+    let n = a[0].len();
+    let mut c = vec![vec![zero::<T>(); n]; lg + 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b[..=lg - i].iter().enumerate() {
+            for ((c, a), b) in c[i + j].iter_mut().zip(a).zip(b) {
+                *c += a.clone() * b.clone();
+            }
+        }
+    }
+    c
+}
+
+// 各 S について、定数項が 1 の多項式の逆元
+fn ranked_inv<T>(a: Vec<Vec<T>>) -> Vec<Vec<T>>
+where
+    T: Ring + Clone,
+{
+    let lg = a.len() - 1;
+    let n = a[0].len();
+    let mut c = vec![vec![zero::<T>(); n]; lg + 1];
+    c[0] = vec![one(); n];
+    for r in 1..=lg {
+        let mut t = vec![zero::<T>(); n];
+        for i in 1..=r {
+            for ((t, a), c) in t.iter_mut().zip(&a[i]).zip(&c[r - i]) {
+                *t -= a.clone() * c.clone();
+            }
+        }
+        c[r] = t;
+    }
+    c
+}
+
 #[test]
 fn test_subset_convolution() {
     use crate::other::Fp;
@@ -44,3 +200,111 @@ fn test_subset_convolution() {
 
     assert_eq!(c, ans);
 }
+
+#[test]
+fn test_fp_subset_convolution() {
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Fp;
+
+    for _ in 0..100 {
+        let n = 1 << rand_int(0..8);
+        let a: Vec<Fp> = (0..n).map(|_| random()).collect();
+        let b: Vec<Fp> = (0..n).map(|_| random()).collect();
+        assert_eq!(
+            subset_convolution(a.clone(), b.clone()),
+            fp_subset_convolution(a, b)
+        );
+    }
+}
+
+#[test]
+fn test_set_power_series() {
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Fp;
+    use std::num::Wrapping;
+
+    fn naive<T: Ring + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        let n = a.len();
+        let mut c = vec![zero::<T>(); n];
+        for s in 0..n {
+            let mut t = s;
+            loop {
+                c[s] += a[t].clone() * b[s ^ t].clone();
+                if t == 0 {
+                    break;
+                }
+                t = (t - 1) & s;
+            }
+        }
+        c
+    }
+
+    fn naive_composition<T: CommutativeRing + Clone>(f: &[T], a: &[T]) -> Vec<T> {
+        let mut res = vec![zero::<T>(); a.len()];
+        for c in f.iter().rev() {
+            res = naive(&res, a);
+            res[0] += c.clone();
+        }
+        res
+    }
+
+    for _ in 0..100 {
+        let n = 1 << rand_int(0..7);
+        let a: Vec<Fp> = (0..n).map(|_| random()).collect();
+        let b: Vec<Fp> = (0..n).map(|_| random()).collect();
+        assert_eq!(subset_convolution(a.clone(), b.clone()), naive(&a, &b));
+
+        let mut a = a;
+        a[0] = Fp::new(0);
+        let f: Vec<Fp> = (0..rand_int(0..10)).map(|_| random()).collect();
+        assert_eq!(
+            subset_composition(&f.clone().into(), a.clone()),
+            naive_composition(&f, &a)
+        );
+
+        // exp(a) = Σ a^k / k!
+        let mut fact = Fp::new(1);
+        let e: Vec<Fp> = (0..8)
+            .map(|k| {
+                if k != 0 {
+                    fact *= Fp::from(k as u32);
+                }
+                Fp::new(1) / fact
+            })
+            .collect();
+        let exp = subset_exp(a.clone());
+        assert_eq!(exp, naive_composition(&e, &a));
+        assert_eq!(subset_log(exp), a);
+
+        let a: Vec<Wrapping<i64>> = (0..n)
+            .map(|s| {
+                if s == 0 {
+                    Wrapping(0)
+                } else {
+                    Wrapping(random())
+                }
+            })
+            .collect();
+        let f: Vec<Wrapping<i64>> = (0..rand_int(0..10)).map(|_| Wrapping(random())).collect();
+        assert_eq!(
+            subset_composition(&f.clone().into(), a.clone()),
+            naive_composition(&f, &a)
+        );
+        let exp = subset_exp(a.clone());
+        assert_eq!(subset_log(exp), a);
+    }
+
+    // 連結なラベル付きグラフの数 (OEIS A001187)
+    let n = 6;
+    let g: Vec<Wrapping<i64>> = (0..1usize << n)
+        .map(|s| {
+            let k = s.count_ones();
+            Wrapping(1 << (k * k.saturating_sub(1) / 2))
+        })
+        .collect();
+    let c = subset_log(g);
+    let ans = [0, 1, 1, 4, 38, 728, 26704];
+    for k in 0..=n {
+        assert_eq!(c[(1 << k) - 1], Wrapping(ans[k]));
+    }
+}
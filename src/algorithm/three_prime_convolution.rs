@@ -0,0 +1,237 @@
+/*
+
+Description
+
+a: 長さ n の整数列
+b: 長さ m の整数列
+
+c_k := Σ_{i+j=k} a_i b_j を計算する。
+
+mod_convolution(a, b, q): c_k mod q
+mod_int_convolution(a, b): ModInt<M> の列に対する畳み込み
+i64_convolution(a, b): c_k 、ただし c_k が i64 に収まる場合に限り正しい
+u128_convolution(a, b): c_k 、ただし c_k < P_1 P_2 P_3 の場合に限り正しい
+checked_i64_convolution, checked_u128_convolution:
+    正しく計算できない可能性がある場合、あるいは結果が型に収まらない場合 None を返す。
+
+時間計算量: Θ((n + m) log(n + m))
+
+3 つの素数 P_1, P_2, P_3 を法として fp_convolution を行い、
+Garner のアルゴリズムで c_k mod P_1 P_2 P_3 を復元する。
+P_1 P_2 P_3 ≒ 2^88.2 であるから、q < 2^32 ならば mod_convolution は常に正しい。
+n + m - 1 <= 2^23 を仮定している。
+ただし mod_convolution はより長い入力を長さ 2^22 ごとに区切って計算する。
+mod_int_convolution は長さが 2^((M - 1) の 2 進付値) 以下ならば fp_convolution を直接用い、
+そうでなければ mod_convolution を用いる。
+
+*/
+
+use crate::algorithm::number_theoretic_transform::{fp_convolution, with_plan, NttPlan};
+use crate::other::algebraic::one;
+use crate::other::fp::ModInt;
+use std::cmp::min;
+use std::convert::TryFrom;
+
+const P1: u32 = 998244353;
+const P2: u32 = 754974721;
+const P3: u32 = 469762049;
+const MOD: u128 = P1 as u128 * P2 as u128 * P3 as u128;
+const LIMIT: usize = 1 << 23;
+
+pub fn mod_convolution(a: &[u32], b: &[u32], q: u32) -> Vec<u32> {
+    assert_ne!(q, 0);
+    if a.len() + b.len() > LIMIT + 1 {
+        let l = LIMIT / 2;
+        let mut c = vec![0; a.len() + b.len() - 1];
+        for (i, a) in a.chunks(l).enumerate() {
+            for (j, b) in b.chunks(l).enumerate() {
+                for (c, d) in c[(i + j) * l..].iter_mut().zip(mod_convolution(a, b, q)) {
+                    *c = ((*c as u64 + d as u64) % q as u64) as u32;
+                }
+            }
+        }
+        return c;
+    }
+    three_prime_convolution(a, b)
+        .into_iter()
+        .map(|c| (c % q as u128) as u32)
+        .collect()
+}
+
+pub fn mod_int_convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    if let Some(ntt) = Ntt::<M>::CONVOLUTION {
+        if a.len() + b.len() <= (1 << (M - 1).trailing_zeros()) + 1 {
+            return ntt(a, b);
+        }
+    }
+    let a: Vec<u32> = a.iter().map(|x| x.0).collect();
+    let b: Vec<u32> = b.iter().map(|x| x.0).collect();
+    mod_convolution(&a, &b, M).into_iter().map(ModInt).collect()
+}
+
+type Convolution<T> = fn(&[T], &[T]) -> Vec<T>;
+
+fn ntt_convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    with_plan(|plan: &mut NttPlan<M>| plan.convolution(a, b))
+}
+
+struct Ntt<const M: u32>;
+
+impl<const M: u32> Ntt<M> {
+    // NttPlan は偶数の法では実体化できないので、関数ポインタを介して避ける
+    const CONVOLUTION: Option<Convolution<ModInt<M>>> = if M % 2 == 1 {
+        Some(ntt_convolution::<M>)
+    } else {
+        None
+    };
+}
+
+pub fn i64_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    three_prime_convolution(a, b)
+        .into_iter()
+        .map(|c| signed(c) as i64)
+        .collect()
+}
+
+pub fn u128_convolution(a: &[u64], b: &[u64]) -> Vec<u128> {
+    three_prime_convolution(a, b)
+}
+
+pub fn checked_i64_convolution(a: &[i64], b: &[i64]) -> Option<Vec<i64>> {
+    let bound = abs_bound(
+        a.iter().map(|x| x.unsigned_abs()),
+        b.iter().map(|x| x.unsigned_abs()),
+    )?;
+    if bound > MOD / 2 {
+        return None;
+    }
+    three_prime_convolution(a, b)
+        .into_iter()
+        .map(|c| i64::try_from(signed(c)).ok())
+        .collect()
+}
+
+pub fn checked_u128_convolution(a: &[u64], b: &[u64]) -> Option<Vec<u128>> {
+    let bound = abs_bound(a.iter().copied(), b.iter().copied())?;
+    if bound >= MOD {
+        return None;
+    }
+    Some(three_prime_convolution(a, b))
+}
+
+// |c_k| の上界 max|a| max|b| min(n, m)
+fn abs_bound<I, J>(a: I, b: J) -> Option<u128>
+where
+    I: ExactSizeIterator<Item = u64>,
+    J: ExactSizeIterator<Item = u64>,
+{
+    let len = min(a.len(), b.len()) as u128;
+    let a = a.max().unwrap_or(0) as u128;
+    let b = b.max().unwrap_or(0) as u128;
+    (a * b).checked_mul(len)
+}
+
+fn signed(c: u128) -> i128 {
+    if c > MOD / 2 {
+        c as i128 - MOD as i128
+    } else {
+        c as i128
+    }
+}
+
+fn three_prime_convolution<T>(a: &[T], b: &[T]) -> Vec<u128>
+where
+    T: Copy,
+    ModInt<P1>: From<T>,
+    ModInt<P2>: From<T>,
+    ModInt<P3>: From<T>,
+{
+    fn convolve<const M: u32, T>(a: &[T], b: &[T]) -> Vec<ModInt<M>>
+    where
+        T: Copy,
+        ModInt<M>: From<T>,
+    {
+        fp_convolution(
+            a.iter().map(|&x| x.into()).collect(),
+            b.iter().map(|&x| x.into()).collect(),
+        )
+    }
+
+    let c1 = convolve::<P1, T>(a, b);
+    let c2 = convolve::<P2, T>(a, b);
+    let c3 = convolve::<P3, T>(a, b);
+
+    let inv1 = one::<ModInt<P2>>() / ModInt::new(P1);
+    let inv12 = one::<ModInt<P3>>() / (ModInt::new(P1) * ModInt::new(P2));
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((x1, x2), x3)| {
+            let t1 = (x2 - ModInt::new(x1.0)) * inv1;
+            let t2 = (x3 - ModInt::new(x1.0) - ModInt::new(P1) * ModInt::new(t1.0)) * inv12;
+            x1.0 as u128 + P1 as u128 * t1.0 as u128 + P1 as u128 * P2 as u128 * t2.0 as u128
+        })
+        .collect()
+}
+
+#[test]
+fn test_three_prime_convolution() {
+    use crate::other::rand::rand_int;
+
+    fn naive<T: Copy, U>(a: &[T], b: &[T], f: impl Fn(T, T) -> U) -> Vec<U>
+    where
+        U: Copy + std::ops::Add<Output = U> + Default,
+    {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut c = vec![U::default(); a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] = c[i + j] + f(x, y);
+            }
+        }
+        c
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..50);
+        let m = rand_int(0..50);
+
+        let q = match rand_int(0..3) {
+            0 => 1_000_000_007,
+            1 => u32::MAX,
+            _ => rand_int(1..u32::MAX),
+        };
+        let a: Vec<u32> = (0..n).map(|_| rand_int(0..u32::MAX)).collect();
+        let b: Vec<u32> = (0..m).map(|_| rand_int(0..u32::MAX)).collect();
+        let c: Vec<u32> = naive(&a, &b, |x, y| x as u128 * y as u128)
+            .into_iter()
+            .map(|c| (c % q as u128) as u32)
+            .collect();
+        assert_eq!(mod_convolution(&a, &b, q), c);
+
+        let a: Vec<i64> = (0..n).map(|_| rand_int(-1 << 30..1 << 30)).collect();
+        let b: Vec<i64> = (0..m).map(|_| rand_int(-1 << 30..1 << 30)).collect();
+        let c = naive(&a, &b, |x, y| x * y);
+        assert_eq!(i64_convolution(&a, &b), c);
+        assert_eq!(checked_i64_convolution(&a, &b), Some(c));
+
+        let a: Vec<u64> = (0..n).map(|_| rand_int(0..1 << 40)).collect();
+        let b: Vec<u64> = (0..m).map(|_| rand_int(0..1 << 40)).collect();
+        let c = naive(&a, &b, |x, y| x as u128 * y as u128);
+        assert_eq!(u128_convolution(&a, &b), c);
+        assert_eq!(checked_u128_convolution(&a, &b), Some(c));
+    }
+
+    let a = vec![i64::MAX, i64::MIN];
+    assert_eq!(checked_i64_convolution(&a, &[1]), Some(a.clone()));
+    assert_eq!(checked_i64_convolution(&a, &[-1]), None);
+    assert_eq!(checked_i64_convolution(&a, &[2]), None);
+    assert_eq!(checked_i64_convolution(&[1 << 40; 2], &[1 << 40; 2]), None);
+    assert_eq!(
+        checked_u128_convolution(&[1 << 43; 2], &[1 << 43; 2]),
+        Some(vec![1 << 86, 1 << 87, 1 << 86])
+    );
+    assert_eq!(checked_u128_convolution(&[u64::MAX], &[u64::MAX]), None);
+}
@@ -21,7 +21,7 @@ m: |P|
 n >= m
 
 S の各位置が P にマッチするか確率的に判定する。? は任意の文字とマッチする。
-誤ってマッチしていると判定する確率は O(1 / 2^w)
+各位置について、誤ってマッチしていると判定する確率は 2 / p < 2^-29 以下
 
 時間計算量: Θ(n log(n))
 
@@ -37,8 +37,10 @@ S'_i := 0 (if S_i = '?') 1 (otherwise) とする。
 本実装はこれらを元にした変種である。
 新たな変数列 (x_i) を用意し、全ての文字も別々の変数と考える。
 文字列 S, T がマッチ ⇔ Σ x_i S'_i T'_i (S_i - T_i) = 0
-これは高々 2 次の多項式になるので、各変数にランダムな F_q の元を割り当てれば
+これは高々 2 次の多項式になるので、各変数にランダムな F_p の元を割り当てれば
 Schwartz-Zippel lemma から高確率で非零判定が可能である。
+p は [2^30, 2^31) からランダムに選んだ素数で、計算中は DynamicModInt の法を p にする。
+p は NTT に適した素数とは限らないので、畳み込みには mod_convolution を用いる。
 競技プログラミング的視点では、畳み込みの回数が 3 回から 2 回に減ること、
 σ が大きくても問題ない部分が利点となると考えている。
 
@@ -55,8 +57,6 @@ None を wildcard, Some(c) を通常の文字としている。
 */
 
 pub fn wildcard_matching(s: &[Option<usize>], p: &[Option<usize>]) -> Vec<bool> {
-    assert_eq!(crate::other::fp::P, 998244353);
-
     let n = s.len();
     let m = p.len();
     assert!(1 <= m && m <= n);
@@ -67,40 +67,51 @@ pub fn wildcard_matching(s: &[Option<usize>], p: &[Option<usize>]) -> Vec<bool>
         assert!(c.map_or(true, |c| c < n + m));
     }
 
-    use crate::algorithm::number_theoretic_transform::fp_convolution;
-    use crate::other::rand::random;
-    use crate::other::Fp;
-
-    let x: Vec<Fp> = (0..n + m).map(|_| random()).collect();
-    let t: Vec<Fp> = (0..m).map(|_| random()).collect();
-
-    let mut res = vec![Fp(0); n + m - 1];
-    {
-        let a: Vec<_> = s.iter().map(|c| c.map_or(Fp(0), |i| x[i])).collect();
-        let b: Vec<_> = p
-            .iter()
-            .rev()
-            .zip(&t)
-            .map(|(c, t)| c.map_or(Fp(0), |_| *t))
-            .collect();
-        for (res, c) in res.iter_mut().zip(fp_convolution(Fp(3), a, b)) {
-            *res += c;
+    use crate::algorithm::three_prime_convolution::mod_convolution;
+    use crate::other::dynamic_mod_int::with_modulus;
+    use crate::other::is_prime::is_prime;
+    use crate::other::rand::{rand_int, random};
+    use crate::other::DynamicModInt as Fp;
+
+    let q = loop {
+        let q: u32 = rand_int(1 << 30..1 << 31);
+        if is_prime(q.into()) {
+            break q;
         }
-    }
-    {
-        let a: Vec<_> = s.iter().map(|c| c.map_or(Fp(0), |_| Fp(1))).collect();
-        let b: Vec<_> = p
-            .iter()
-            .rev()
-            .zip(&t)
-            .map(|(c, t)| c.map_or(Fp(0), |i| x[i] * *t))
-            .collect();
-        for (res, c) in res.iter_mut().zip(fp_convolution(Fp(3), a, b)) {
-            *res -= c;
+    };
+
+    with_modulus(q, || {
+        let x: Vec<Fp> = (0..n + m).map(|_| random()).collect();
+        let t: Vec<Fp> = (0..m).map(|_| random()).collect();
+
+        let mut res = vec![Fp::new(0); n + m - 1];
+        {
+            let a: Vec<_> = s.iter().map(|c| c.map_or(0, |i| x[i].0)).collect();
+            let b: Vec<_> = p
+                .iter()
+                .rev()
+                .zip(&t)
+                .map(|(c, t)| c.map_or(0, |_| t.0))
+                .collect();
+            for (res, c) in res.iter_mut().zip(mod_convolution(&a, &b, q)) {
+                *res += Fp(c);
+            }
+        }
+        {
+            let a: Vec<_> = s.iter().map(|c| c.map_or(0, |_| 1)).collect();
+            let b: Vec<_> = p
+                .iter()
+                .rev()
+                .zip(&t)
+                .map(|(c, t)| c.map_or(0, |i| (x[i] * *t).0))
+                .collect();
+            for (res, c) in res.iter_mut().zip(mod_convolution(&a, &b, q)) {
+                *res -= Fp(c);
+            }
         }
-    }
 
-    res[m - 1..n].iter().map(|v| *v == Fp(0)).collect()
+        res[m - 1..n].iter().map(|v| *v == Fp::new(0)).collect()
+    })
 }
 
 #[test]
@@ -1,4 +1,28 @@
-use crate::other::algebraic::{Abelian, CommutativeSemigroup};
+/*
+
+Description
+
+T: 可換半群 (zeta), Abel 群 (mobius, walsh_hadamard), 環 (and/or_convolution, scaled_xor_convolution),
+   体 (inverse_walsh_hadamard, xor_convolution)
+a, b: 長さ 2^n の T の列
+
+subset_zeta(a): b_S := Σ_{T⊆S} a_T で a を置き換える。
+subset_mobius(a): subset_zeta の逆変換
+superset_zeta(a): b_S := Σ_{T⊇S} a_T で a を置き換える。
+superset_mobius(a): superset_zeta の逆変換
+walsh_hadamard(a): b_S := Σ_T (-1)^|S∩T| a_T で a を置き換える。
+inverse_walsh_hadamard(a): walsh_hadamard の逆変換 (2^n で割る)
+
+and_convolution(a, b): c_S := Σ_{T∩U=S} a_T b_U
+or_convolution(a, b): c_S := Σ_{T∪U=S} a_T b_U
+xor_convolution(a, b): c_S := Σ_{T⊕U=S} a_T b_U 、T の標数は 2 でない必要がある。
+scaled_xor_convolution(a, b): 2^n c_S 、2 で割らないので T は任意の環でよい。
+
+時間計算量: Θ(2^n n) 回の T の演算
+
+*/
+
+use crate::other::algebraic::{one, Abelian, CommutativeSemigroup, Field, Ring};
 
 pub fn subset_zeta<T>(a: &mut Vec<T>)
 where
@@ -31,3 +55,216 @@ where
         }
     }
 }
+
+pub fn superset_zeta<T>(a: &mut [T])
+where
+    T: CommutativeSemigroup + Clone,
+{
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    for w in (0..n.trailing_zeros()).map(|i| 1 << i) {
+        for k in (0..n).step_by(2 * w) {
+            for i in 0..w {
+                let t = a[k + w + i].clone();
+                a[k + i] += t;
+            }
+        }
+    }
+}
+
+pub fn superset_mobius<T>(a: &mut [T])
+where
+    T: Abelian + Clone,
+{
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    for w in (0..n.trailing_zeros()).map(|i| 1 << i) {
+        for k in (0..n).step_by(2 * w) {
+            for i in 0..w {
+                let t = a[k + w + i].clone();
+                a[k + i] -= t;
+            }
+        }
+    }
+}
+
+pub fn walsh_hadamard<T>(a: &mut [T])
+where
+    T: Abelian + Clone,
+{
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    for w in (0..n.trailing_zeros()).map(|i| 1 << i) {
+        for k in (0..n).step_by(2 * w) {
+            for i in 0..w {
+                let x = a[k + i].clone();
+                let y = a[k + w + i].clone();
+                a[k + i] = x.clone() + y.clone();
+                a[k + w + i] = x - y;
+            }
+        }
+    }
+}
+
+pub fn inverse_walsh_hadamard<T>(a: &mut [T])
+where
+    T: Field + Clone,
+{
+    walsh_hadamard(a);
+    let mut n: T = one();
+    for _ in 0..a.len().trailing_zeros() {
+        n = n.clone() + n;
+    }
+    let inv = T::one() / n;
+    for a in a.iter_mut() {
+        *a *= inv.clone();
+    }
+}
+
+pub fn and_convolution<T>(mut a: Vec<T>, mut b: Vec<T>) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    superset_zeta(&mut a);
+    superset_zeta(&mut b);
+    let mut c = pointwise(a, b);
+    superset_mobius(&mut c);
+    c
+}
+
+pub fn or_convolution<T>(mut a: Vec<T>, mut b: Vec<T>) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    subset_zeta(&mut a);
+    subset_zeta(&mut b);
+    let mut c = pointwise(a, b);
+    subset_mobius(&mut c);
+    c
+}
+
+pub fn xor_convolution<T>(mut a: Vec<T>, mut b: Vec<T>) -> Vec<T>
+where
+    T: Field + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    walsh_hadamard(&mut a);
+    walsh_hadamard(&mut b);
+    let mut c = pointwise(a, b);
+    inverse_walsh_hadamard(&mut c);
+    c
+}
+
+pub fn scaled_xor_convolution<T>(mut a: Vec<T>, mut b: Vec<T>) -> Vec<T>
+where
+    T: Ring + Clone,
+{
+    assert_eq!(a.len(), b.len());
+    walsh_hadamard(&mut a);
+    walsh_hadamard(&mut b);
+    let mut c = pointwise(a, b);
+    walsh_hadamard(&mut c);
+    c
+}
+
+fn pointwise<T>(a: Vec<T>, b: Vec<T>) -> Vec<T>
+where
+    T: Ring,
+{
+    a.into_iter().zip(b).map(|(a, b)| a * b).collect()
+}
+
+#[test]
+fn test_zeta_transform() {
+    use crate::other::rand::{rand_int, random};
+    use crate::other::Fp;
+    use std::num::Wrapping;
+
+    fn brute<T, F>(a: &[T], b: &[T], op: F) -> Vec<T>
+    where
+        T: Ring + Clone,
+        F: Fn(usize, usize) -> usize,
+    {
+        let mut c = vec![T::zero(); a.len()];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                c[op(i, j)] += a.clone() * b.clone();
+            }
+        }
+        c
+    }
+
+    for _ in 0..100 {
+        let n = 1 << rand_int(0..7);
+        let a: Vec<Fp> = (0..n).map(|_| random()).collect();
+        let b: Vec<Fp> = (0..n).map(|_| random()).collect();
+
+        let mut z = a.clone();
+        subset_zeta(&mut z);
+        let naive: Vec<Fp> = (0..n)
+            .map(|s| (0..n).filter(|&t| t & s == t).map(|t| a[t]).sum())
+            .collect();
+        assert_eq!(z, naive);
+        subset_mobius(&mut z);
+        assert_eq!(z, a);
+
+        let mut z = a.clone();
+        superset_zeta(&mut z);
+        let naive: Vec<Fp> = (0..n)
+            .map(|s| (0..n).filter(|&t| t & s == s).map(|t| a[t]).sum())
+            .collect();
+        assert_eq!(z, naive);
+        superset_mobius(&mut z);
+        assert_eq!(z, a);
+
+        let mut z = a.clone();
+        walsh_hadamard(&mut z);
+        let naive: Vec<Fp> = (0..n)
+            .map(|s| {
+                (0..n)
+                    .map(|t| {
+                        if (s & t).count_ones() % 2 == 0 {
+                            a[t]
+                        } else {
+                            -a[t]
+                        }
+                    })
+                    .sum()
+            })
+            .collect();
+        assert_eq!(z, naive);
+        inverse_walsh_hadamard(&mut z);
+        assert_eq!(z, a);
+
+        assert_eq!(
+            and_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i & j)
+        );
+        assert_eq!(
+            or_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i | j)
+        );
+        assert_eq!(
+            xor_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i ^ j)
+        );
+
+        let a: Vec<Wrapping<i64>> = (0..n).map(|_| Wrapping(random())).collect();
+        let b: Vec<Wrapping<i64>> = (0..n).map(|_| Wrapping(random())).collect();
+        assert_eq!(
+            and_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i & j)
+        );
+        assert_eq!(
+            or_convolution(a.clone(), b.clone()),
+            brute(&a, &b, |i, j| i | j)
+        );
+        let c: Vec<Wrapping<i64>> = brute(&a, &b, |i, j| i ^ j)
+            .into_iter()
+            .map(|c| c * Wrapping(n as i64))
+            .collect();
+        assert_eq!(scaled_xor_convolution(a, b), c);
+    }
+}
@@ -65,7 +65,7 @@ fn test_fenwick_tree() {
         let q = 100;
 
         let mut ft = FenwickTree::new(n);
-        let mut v = vec![Fp(0); n].into_boxed_slice();
+        let mut v = vec![Fp::new(0); n].into_boxed_slice();
         for _ in 0..q {
             match rand_int(0..4) {
                 0 => {
@@ -74,7 +74,7 @@ fn test_fenwick_tree() {
                 }
                 1 => {
                     let index = rand_int(0..n);
-                    let x = Fp(rand_int(0..P));
+                    let x = Fp::new(rand_int(0..P));
                     ft.add(index, &x);
                     v[index] += x;
                 }
@@ -84,7 +84,7 @@ fn test_fenwick_tree() {
                 }
                 3 => {
                     let index = rand_int(0..n);
-                    let x = Fp(rand_int(0..P));
+                    let x = Fp::new(rand_int(0..P));
                     ft.set(index, x);
                     v[index] = x;
                 }
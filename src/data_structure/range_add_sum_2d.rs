@@ -209,7 +209,7 @@ fn test_range_add_sum_2d() {
         let q = 100;
 
         let mut a = RangeAddSum2d::<Fp>::new(h, w);
-        let mut b = vec![vec![Fp(0); w]; h];
+        let mut b = vec![vec![Fp::new(0); w]; h];
 
         for _ in 0..q {
             let x = rand_range(0..h);
@@ -221,7 +221,7 @@ fn test_range_add_sum_2d() {
                     .sum();
                 assert_eq!(a.sum(x, y), ans);
             } else {
-                let v = Fp(rand_int(0..P));
+                let v = Fp::new(rand_int(0..P));
                 a.add(x.clone(), y.clone(), &v);
                 for b in &mut b[x.clone()] {
                     for b in &mut b[y.clone()] {
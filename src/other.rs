@@ -1,32 +1,54 @@
 pub mod algebraic;
 pub mod bit;
+pub mod chirp_z;
 pub mod cmp_assign;
 pub mod cmp_by_key;
+pub mod complex;
 pub mod connectivity;
+pub mod crt;
+pub mod discrete_log;
 pub mod dual;
+pub mod dynamic_mod_int;
+pub mod extension_field;
 pub mod extgcd;
+pub mod factorize;
+pub mod formal_power_series;
 pub mod fp;
 pub mod fp_utils;
 pub mod gcd;
 pub mod gf2m;
 pub mod integer_sqrt;
+pub mod is_prime;
 pub mod itertools;
+pub mod linear_algebra;
 pub mod linked_list;
 pub mod matrix;
 pub mod min;
 pub mod mod_inv;
+pub mod mod_pow;
+pub mod mod_sqrt;
+pub mod montgomery_mod_int;
 pub mod multiplicative;
+pub mod multipoint_evaluation;
 pub mod polynomial;
+pub mod polynomial_composition;
+pub mod polynomial_division;
+pub mod polynomial_factorization;
 pub mod pow;
+pub mod primitive_root;
 pub mod queue;
 pub mod rand;
 pub mod recurse;
 pub mod suspension;
+pub mod taylor_shift;
 
 pub use cmp_by_key::CmpByKey;
+pub use complex::Complex;
 pub use connectivity::is_connected;
 pub use dual::Dual;
-pub use fp::Fp;
+pub use dynamic_mod_int::DynamicModInt;
+pub use extension_field::ExtensionField;
+pub use fp::{Fp, ModInt};
 pub use gcd::gcd;
 pub use gf2m::GF2m;
 pub use min::Min;
@@ -1,4 +1,5 @@
 use std::marker::Sized;
+use std::num::Wrapping;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 pub trait Zero: Add<Output = Self> + Sized {
@@ -15,7 +16,7 @@ macro_rules! impl_zero {
                 }
                 fn is_zero(&self) -> bool {
                     *self == 0
-                } 
+                }
             }
         )*
     };
@@ -25,8 +26,41 @@ impl_zero! {u64, i64, usize, isize}
 
 pub trait One: Mul<Output = Self> + Sized {
     fn one() -> Self;
+
+    // 列の畳み込みを高速に計算できる型はこれを上書きする
+    // None を返すと Polynomial の積は愚直に計算される
+    fn convolution(_a: &[Self], _b: &[Self]) -> Option<Vec<Self>> {
+        None
+    }
 }
 
+macro_rules! impl_wrapping {
+    ($($t: ty),*) => {
+        $(
+            impl Zero for Wrapping<$t> {
+                fn zero() -> Self {
+                    Wrapping(0)
+                }
+                fn is_zero(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+
+            impl One for Wrapping<$t> {
+                fn one() -> Self {
+                    Wrapping(1)
+                }
+
+                fn convolution(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+                    Some(crate::algorithm::karatsuba::karatsuba(a, b))
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping! {u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize}
+
 pub fn zero<T>() -> T
 where
     T: Zero,
@@ -0,0 +1,188 @@
+/*
+
+Reference
+
+[1] Bluestein, L. (1970).
+    A linear filtering approach to the computation of discrete Fourier transform.
+    IEEE Transactions on Audio and Electroacoustics, 18(4), 451-455.
+
+[2] Bostan, A., & Schost, É. (2005).
+    Polynomial evaluation and interpolation on special sets of points.
+    Journal of Complexity, 21(4), 420-446.
+
+
+Description
+
+f: F_M 上の多項式、長さ n
+a, r: F_M の元
+m: 非負整数
+
+chirp_z(f, a, r, m): (f(a), f(a r), ..., f(a r^(m-1)))
+interpolate_geometric(a, r, y): f(a r^k) = y_k (0 <= k < len(y)) を満たす
+                                長さ len(y) の多項式 f
+                                a ≠ 0 かつ a r^k が相異なる必要がある。
+
+時間計算量
+chirp_z: O(M(n + m))
+interpolate_geometric: O(M(len(y)))
+ただし M(n) は多項式乗算の計算量
+
+i k = C(i + k, 2) - C(i, 2) - C(k, 2) を用いて、評価を一回の畳み込みに帰着する。
+補間は Lagrange 補間の重みが等比数列の点では O(n) で求まることを用いる。
+Σ_k c_k / (x - r^k) mod x^n は r^-1 を公比とする chirp_z で求まり、
+これに Π_k (x - r^k) を掛けて f を得る。
+
+*/
+
+use crate::other::algebraic::{One, Zero};
+use crate::other::fp::ModInt;
+use crate::other::Polynomial;
+
+impl<const M: u32> Polynomial<ModInt<M>> {
+    pub fn chirp_z(&self, a: ModInt<M>, r: ModInt<M>, m: usize) -> Vec<ModInt<M>> {
+        let n = self.coef.len();
+        if n == 0 || m == 0 {
+            return vec![ModInt::zero(); m];
+        }
+        if r.is_zero() {
+            let mut res = vec![self.coef[0]; m];
+            res[0] = self.evaluate(&a);
+            return res;
+        }
+        // w_t = r^C(t, 2)
+        let w = triangular_powers(r, n + m - 1);
+        let ri = ModInt::one() / r;
+        let wi = triangular_powers(ri, n.max(m));
+        let mut ap = ModInt::one();
+        let mut u = Vec::with_capacity(n);
+        for (&c, &w) in self.coef.iter().zip(&wi) {
+            u.push(c * ap * w);
+            ap *= a;
+        }
+        u.reverse();
+        let u = Self::from(u);
+        let s = u * Self::from(w);
+        (0..m).map(|k| s.coef[n - 1 + k] * wi[k]).collect()
+    }
+
+    pub fn interpolate_geometric(a: ModInt<M>, r: ModInt<M>, y: &[ModInt<M>]) -> Self {
+        let n = y.len();
+        if n == 0 {
+            return Self::new();
+        }
+        assert!(!a.is_zero() && !r.is_zero());
+
+        // p_t = Π_{s=1}^{t} (r^s - 1)
+        let mut p = vec![ModInt::one(); n];
+        let mut rp = ModInt::one();
+        for t in 1..n {
+            rp *= r;
+            p[t] = p[t - 1] * (rp - ModInt::one());
+        }
+        assert!(!p[n - 1].is_zero());
+
+        // c_k = y_k / Π_{j≠k} (r^k - r^j)
+        //     = y_k / (r^(C(k, 2) + k (n - 1 - k)) (-1)^(n - 1 - k) p_k p_(n-1-k))
+        let w = triangular_powers(r, n);
+        let mut rk = ModInt::one();
+        let c: Self = (0..n)
+            .map(|k| {
+                let mut d = w[k] * rk.pow((n - 1 - k) as u64) * p[k] * p[n - 1 - k];
+                if !(n - 1 - k).is_multiple_of(2) {
+                    d = -d;
+                }
+                rk *= r;
+                y[k] / d
+            })
+            .collect();
+
+        // Σ_k c_k / (x - r^k) = -Σ_i x^i Σ_k c_k r^(-k(i+1))
+        let ri = ModInt::one() / r;
+        let h: Self = c.chirp_z(ri, ri, n).into_iter().map(|x| -x).collect();
+        let g = (geometric_product(r, n) * h).truncated(n);
+
+        // g(x) = f(a x)
+        let ai = ModInt::one() / a;
+        let mut ap = ModInt::one();
+        g.coef
+            .into_iter()
+            .map(|x| {
+                let t = x * ap;
+                ap *= ai;
+                t
+            })
+            .collect()
+    }
+}
+
+// (r^C(0, 2), r^C(1, 2), ..., r^C(len - 1, 2))
+fn triangular_powers<const M: u32>(r: ModInt<M>, len: usize) -> Vec<ModInt<M>> {
+    let mut res = Vec::with_capacity(len);
+    let (mut w, mut rt) = (ModInt::one(), ModInt::one());
+    for _ in 0..len {
+        res.push(w);
+        w *= rt;
+        rt *= r;
+    }
+    res
+}
+
+// Π_{j<n} (x - r^j)
+fn geometric_product<const M: u32>(r: ModInt<M>, n: usize) -> Polynomial<ModInt<M>> {
+    if n == 0 {
+        return ModInt::one().into();
+    }
+    if !n.is_multiple_of(2) {
+        let h = geometric_product(r, n - 1);
+        return h * Polynomial::from(vec![-r.pow((n - 1) as u64), ModInt::one()]);
+    }
+    // Π_{j<m} (x - r^(m+j)) = r^(m^2) h(x / r^m)
+    let m = n / 2;
+    let h = geometric_product(r, m);
+    let rm = r.pow(m as u64);
+    let mut s = ModInt::one();
+    let mut t = h.coef.clone();
+    for c in t.iter_mut().rev() {
+        *c *= s;
+        s *= rm;
+    }
+    h * Polynomial::from(t)
+}
+
+#[test]
+fn test_chirp_z() {
+    use crate::other::rand::{rand_int, random};
+
+    fn test<const M: u32>(n: usize, m: usize) {
+        let f: Polynomial<ModInt<M>> = (0..n).map(|_| random()).collect();
+        let a: ModInt<M> = random();
+        let r: ModInt<M> = random();
+        let naive: Vec<ModInt<M>> = (0..m).map(|k| f.evaluate(&(a * r.pow(k as u64)))).collect();
+        assert_eq!(f.chirp_z(a, r, m), naive);
+        let naive: Vec<ModInt<M>> = (0..m)
+            .map(|k| f.evaluate(&(a * ModInt::new(0).pow(k as u64))))
+            .collect();
+        assert_eq!(f.chirp_z(a, ModInt::new(0), m), naive);
+
+        if a.is_zero() || r.is_zero() {
+            return;
+        }
+        let mut rk = ModInt::one();
+        for _ in 1..n {
+            rk *= r;
+            if rk == ModInt::one() {
+                return;
+            }
+        }
+        let y = f.chirp_z(a, r, n);
+        assert_eq!(Polynomial::interpolate_geometric(a, r, &y).coef, f.coef);
+    }
+
+    for _ in 0..100 {
+        let (n, m) = (rand_int(0..50), rand_int(0..50));
+        test::<998244353>(n, m);
+        test::<1_000_000_007>(n, m);
+        test::<13>(rand_int(0..8), m);
+    }
+    test::<998244353>(1000, 1000);
+}
@@ -0,0 +1,218 @@
+/*
+
+Description
+
+T: 浮動小数点数型
+
+複素数 re + im i を表す。
+Field を実装しているので、Polynomial などの係数として用いることができる。
+
+*/
+
+use crate::other::algebraic::{One, Zero};
+use num_traits::float::Float;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T>
+where
+    T: Float,
+{
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    // e^(i theta)
+    pub fn cis(theta: T) -> Self {
+        let (s, c) = theta.sin_cos();
+        Self::new(c, s)
+    }
+
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::cis(theta) * r
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn abs(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+}
+
+impl<T> From<T> for Complex<T>
+where
+    T: Float,
+{
+    fn from(re: T) -> Self {
+        Self::new(re, T::zero())
+    }
+}
+
+impl<T> Zero for Complex<T>
+where
+    T: Float,
+{
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T> One for Complex<T>
+where
+    T: Float,
+{
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Add for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T> AddAssign for Complex<T>
+where
+    T: Float,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> SubAssign for Complex<T>
+where
+    T: Float,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T> Mul<T> for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl<T> MulAssign for Complex<T>
+where
+    T: Float,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let d = rhs.norm_sqr();
+        let n = self * rhs.conj();
+        Self::new(n.re / d, n.im / d)
+    }
+}
+
+impl<T> Div<T> for Complex<T>
+where
+    T: Float,
+{
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl<T> DivAssign for Complex<T>
+where
+    T: Float,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+#[test]
+fn test_complex() {
+    use crate::other::algebraic::Field;
+
+    fn is_field<T: Field>() {}
+    is_field::<Complex<f64>>();
+    is_field::<Complex<f32>>();
+
+    let close = |a: Complex<f64>, b: Complex<f64>| (a - b).abs() < 1e-12;
+
+    let a = Complex::new(1.0, 2.0);
+    let b = Complex::new(-3.0, 0.5);
+    assert_eq!(a * b, Complex::new(-4.0, -5.5));
+    assert!(close(a / b * b, a));
+    assert!(close(a * a.conj(), Complex::from(a.norm_sqr())));
+    assert!(close(Complex::from_polar(a.abs(), a.arg()), a));
+    let i = Complex::cis(std::f64::consts::FRAC_PI_2);
+    assert!(close(i * i, -Complex::one()));
+}
@@ -0,0 +1,145 @@
+/*
+
+Description
+
+(r_i, m_i): 合同式 x ≡ r_i (mod m_i)
+
+crt(c): 連立合同式を満たす x を (x mod L, L) として返す。ただし L = lcm(m_i) 。
+        解が存在しない場合 Err(CrtError::Inconsistent) 、
+        L が 64 bit に収まらない場合 Err(CrtError::Overflow) を返す。
+        m_i は互いに素でなくともよい。
+crt_merge(a, b): 2 つの合同式を 1 つにまとめる。
+garner(c, m): m_i が互いに素であるとき、0 <= x < Π m_i を満たす解 x の mod m を返す。
+              Π m_i が 64 bit に収まる必要はない。
+
+時間計算量
+crt: O(k log(max m_i))
+garner: O(k^2 + k log(max m_i))
+
+乗算は 128 bit 整数を介して行っている。
+
+*/
+
+use crate::other::gcd;
+use crate::other::mod_pow::mod_mul;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CrtError {
+    Inconsistent,
+    Overflow,
+}
+
+pub fn crt_merge((r1, m1): (u64, u64), (r2, m2): (u64, u64)) -> Result<(u64, u64), CrtError> {
+    assert!(m1 != 0 && m2 != 0);
+    let r1 = r1 % m1;
+    let r2 = r2 % m2;
+    let g = gcd(m1, m2);
+    let d = (r2 as u128 + (m2 - r1 % m2) as u128) % m2 as u128;
+    if !d.is_multiple_of(g as u128) {
+        return Err(CrtError::Inconsistent);
+    }
+    let l = (m1 / g).checked_mul(m2).ok_or(CrtError::Overflow)?;
+    let n = m2 / g;
+    let t = mod_mul(n, (d / g as u128) as u64, mod_inv(m1 / g, n));
+    Ok(((r1 as u128 + m1 as u128 * t as u128) as u64, l))
+}
+
+pub fn crt(c: &[(u64, u64)]) -> Result<(u64, u64), CrtError> {
+    c.iter().try_fold((0, 1), |a, &b| crt_merge(a, b))
+}
+
+pub fn garner(c: &[(u64, u64)], m: u64) -> u64 {
+    assert_ne!(m, 0);
+    // x = Σ t_i Π_{j<i} m_j
+    // coef[j] = Π_{l<i} m_l mod m_j, val[j] = Σ_{l<i} t_l Π_{k<l} m_k mod m_j
+    let mut coef = vec![1 % m; c.len() + 1];
+    let mut val = vec![0; c.len() + 1];
+    let mut mods: Vec<u64> = c.iter().map(|&(_, mi)| mi).collect();
+    mods.push(m);
+    for (i, &(r, mi)) in c.iter().enumerate() {
+        let d = ((r % mi) as u128 + (mi - val[i]) as u128) % mi as u128;
+        let t = mod_mul(mi, d as u64, mod_inv(coef[i], mi));
+        for ((c, v), &mj) in coef[i + 1..]
+            .iter_mut()
+            .zip(&mut val[i + 1..])
+            .zip(&mods[i + 1..])
+        {
+            *v = ((*v as u128 + mod_mul(mj, *c, t) as u128) % mj as u128) as u64;
+            *c = mod_mul(mj, *c, mi);
+        }
+    }
+    val[c.len()]
+}
+
+// a^{-1} mod m, gcd(a, m) = 1
+fn mod_inv(a: u64, m: u64) -> u64 {
+    let (mut x, mut y) = (a as i128 % m as i128, m as i128);
+    let (mut cx, mut cy) = (1i128, 0i128);
+    while x != 0 {
+        let t = y / x;
+        y -= t * x;
+        cy -= t * cx;
+        std::mem::swap(&mut x, &mut y);
+        std::mem::swap(&mut cx, &mut cy);
+    }
+    assert!(m == 1 || y == 1, "not coprime");
+    cy.rem_euclid(m as i128) as u64
+}
+
+#[test]
+fn test_crt() {
+    use crate::other::rand::rand_int;
+
+    for _ in 0..1000 {
+        let c: Vec<(u64, u64)> = (0..rand_int(0..4))
+            .map(|_| {
+                let m = rand_int(1..20);
+                (rand_int(0..100), m)
+            })
+            .collect();
+        let l = c.iter().fold(1, |l, &(_, m)| l / gcd(l, m) * m);
+        let naive = (0..l).find(|&x| c.iter().all(|&(r, m)| x % m == r % m));
+        match naive {
+            Some(x) => assert_eq!(crt(&c), Ok((x, l))),
+            None => assert_eq!(crt(&c), Err(CrtError::Inconsistent)),
+        }
+    }
+
+    let p = 18446744073709551557;
+    let q = 18446744073709551533;
+    assert_eq!(crt(&[(1, p), (1, p)]), Ok((1, p)));
+    assert_eq!(crt(&[(p - 1, p), (0, 2)]), Err(CrtError::Overflow));
+    assert_eq!(
+        crt(&[(3, 1 << 32), (5, 1 << 32)]),
+        Err(CrtError::Inconsistent)
+    );
+    let (x, l) = crt(&[(p - 1, p), (1, 1)]).unwrap();
+    assert_eq!((x, l), (p - 1, p));
+    assert_eq!(crt(&[(2, q - 1), (0, 2)]), Ok((2, q - 1)));
+}
+
+#[test]
+fn test_garner() {
+    use crate::other::rand::rand_int;
+
+    let primes = [998244353, 754974721, 167772161, 469762049];
+    for _ in 0..1000 {
+        let x: u128 = rand_int(0..1 << 100);
+        let c: Vec<(u64, u64)> = primes
+            .iter()
+            .map(|&p| ((x % p as u128) as u64, p))
+            .collect();
+        let m = rand_int(1..u64::MAX);
+        assert_eq!(garner(&c, m), (x % m as u128) as u64);
+    }
+
+    for _ in 0..1000 {
+        let c: Vec<(u64, u64)> = [4, 9, 25, 7, 11]
+            .iter()
+            .map(|&p| (rand_int(0..100), p))
+            .collect();
+        let (x, _) = crt(&c).unwrap();
+        let m = rand_int(1..10000);
+        assert_eq!(garner(&c, m), x % m);
+    }
+}
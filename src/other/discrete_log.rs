@@ -0,0 +1,86 @@
+/*
+
+Description
+
+m: 正整数
+a, b: 整数
+
+a^x ≡ b (mod m) を満たす最小の非負整数 x を計算する。
+存在しない場合 None を返す。
+
+時間計算量: O(sqrt(m) + log(m)^2) (ハッシュ表の操作を O(1) とする)
+
+Baby-step giant-step 法。
+gcd(a, m) ≠ 1 の場合は、両辺を gcd で割ることで互いに素な場合に帰着する。
+
+*/
+
+use crate::other::gcd;
+use crate::other::integer_sqrt::floor_sqrt;
+use crate::other::mod_pow::{mod_mul, mod_pow};
+use std::collections::HashMap;
+
+pub fn discrete_log(mut m: u64, a: u64, b: u64) -> Option<u64> {
+    assert_ne!(m, 0);
+    let a = a % m;
+    let mut b = b % m;
+    let mut k = 1 % m;
+    let mut add = 0;
+    loop {
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if b == k {
+            return Some(add);
+        }
+        if !b.is_multiple_of(g) {
+            return None;
+        }
+        b /= g;
+        m /= g;
+        add += 1;
+        k = mod_mul(m, k, a / g);
+    }
+
+    let n = floor_sqrt(m) + 1;
+    let an = mod_pow(m, a, n);
+    let mut baby = HashMap::new();
+    let mut cur = b;
+    for q in 0..=n {
+        baby.insert(cur, q);
+        cur = mod_mul(m, cur, a);
+    }
+    let mut cur = k;
+    for p in 1..=n {
+        cur = mod_mul(m, cur, an);
+        if let Some(&q) = baby.get(&cur) {
+            return Some(n * p - q + add);
+        }
+    }
+    None
+}
+
+#[test]
+fn test_discrete_log() {
+    use crate::other::rand::rand_int;
+
+    for m in 1..50u64 {
+        for a in 0..m {
+            for b in 0..m {
+                let naive = (0..2 * m).find(|&x| mod_pow(m, a, x) == b);
+                assert_eq!(discrete_log(m, a, b), naive);
+            }
+        }
+    }
+
+    let p = 998244353;
+    for _ in 0..10 {
+        let a = rand_int(1..p);
+        let x = rand_int(0..p);
+        let b = mod_pow(p, a, x);
+        let r = discrete_log(p, a, b).unwrap();
+        assert!(r <= x);
+        assert_eq!(mod_pow(p, a, r), b);
+    }
+}
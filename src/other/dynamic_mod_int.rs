@@ -0,0 +1,322 @@
+/*
+
+Description
+
+実行時に法を定める Z / mZ
+
+法はスレッドごとに保持され、set_modulus で変更する。
+法を変更する前に作られた値を変更後に用いてはならない。
+with_modulus(m, f) は法を m にして f を呼び、終了後 (panic した場合も) 元の法に戻す。
+ライブラリ内部で法を変更する場合はこちらを用いる。
+乗算は Barrett reduction を用いて計算する。
+
+1 <= m < 2^31 を仮定している。
+除算は拡張ユークリッドの互除法で逆元を求めるので、
+m が素数でなくとも除数が m と互いに素であればよい。
+
+*/
+
+use crate::other::algebraic::{One, Zero};
+use std::cell::Cell;
+use std::convert::From;
+use std::iter;
+use std::ops;
+use std::thread_local;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Barrett {
+    m: u32,
+    im: u64,
+}
+
+impl Barrett {
+    pub const fn new(m: u32) -> Self {
+        assert!(1 <= m && m < 1 << 31);
+        Self {
+            m,
+            im: (!0u64 / m as u64).wrapping_add(1),
+        }
+    }
+
+    pub fn modulus(&self) -> u32 {
+        self.m
+    }
+
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        let z = a as u64 * b as u64;
+        let x = ((z as u128 * self.im as u128) >> 64) as u64;
+        let y = x.wrapping_mul(self.m as u64);
+        let v = z.wrapping_sub(y) as u32;
+        if z < y {
+            v.wrapping_add(self.m)
+        } else {
+            v
+        }
+    }
+}
+
+thread_local! {
+    static BARRETT: Cell<Barrett> = const { Cell::new(Barrett::new(998244353)) };
+}
+
+fn barrett() -> Barrett {
+    BARRETT.with(|b| b.get())
+}
+
+pub fn set_modulus(m: u32) {
+    BARRETT.with(|b| b.set(Barrett::new(m)));
+}
+
+pub fn with_modulus<R>(m: u32, f: impl FnOnce() -> R) -> R {
+    struct Restore(Barrett);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            BARRETT.with(|b| b.set(self.0));
+        }
+    }
+    let _restore = Restore(barrett());
+    set_modulus(m);
+    f()
+}
+
+pub fn modulus() -> u32 {
+    barrett().modulus()
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Default)]
+pub struct DynamicModInt(pub u32);
+
+impl DynamicModInt {
+    pub fn new(x: u32) -> Self {
+        Self(x % modulus())
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let b = barrett();
+        let mut x = self.0;
+        let mut res = 1 % b.modulus();
+        while exp != 0 {
+            if exp & 1 != 0 {
+                res = b.mul(res, x);
+            }
+            x = b.mul(x, x);
+            exp /= 2;
+        }
+        Self(res)
+    }
+
+    pub fn inverse(self) -> Self {
+        let m = modulus();
+        let (x, _, g) = crate::other::extgcd::extgcd(self.0 as i64, m as i64);
+        assert_eq!(g, 1, "not invertible");
+        Self(x.rem_euclid(m as i64) as u32)
+    }
+}
+
+impl Zero for DynamicModInt {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for DynamicModInt {
+    fn one() -> Self {
+        Self(1 % modulus())
+    }
+
+    fn convolution(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        use crate::algorithm::three_prime_convolution::mod_convolution;
+        let a: Vec<u32> = a.iter().map(|x| x.0).collect();
+        let b: Vec<u32> = b.iter().map(|x| x.0).collect();
+        Some(
+            mod_convolution(&a, &b, modulus())
+                .into_iter()
+                .map(Self)
+                .collect(),
+        )
+    }
+}
+
+macro_rules! impl_from_int {
+    ($(($ty:ty: $via:ty)),*) => {
+        $(
+            impl From<$ty> for DynamicModInt {
+                fn from(x: $ty) -> Self {
+                    Self((x as $via).rem_euclid(modulus() as $via) as u32)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(
+    (i8: i32),
+    (i16: i32),
+    (i32: i64),
+    (i64: i64),
+    (u8: u32),
+    (u16: u32),
+    (u32: u32),
+    (u64: u64),
+    (isize: i64),
+    (usize: u64)
+);
+
+impl iter::Product for DynamicModInt {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::one(), |b, i| b * i)
+    }
+}
+
+impl iter::Sum for DynamicModInt {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self(0), |b, i| b + i)
+    }
+}
+
+impl ops::Add for DynamicModInt {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl ops::AddAssign for DynamicModInt {
+    fn add_assign(&mut self, rhs: Self) {
+        let m = modulus();
+        self.0 += rhs.0;
+        if self.0 >= m {
+            self.0 -= m;
+        }
+    }
+}
+
+impl ops::Div for DynamicModInt {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+impl ops::DivAssign for DynamicModInt {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl ops::Mul for DynamicModInt {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(barrett().mul(self.0, rhs.0))
+    }
+}
+
+impl ops::MulAssign for DynamicModInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Neg for DynamicModInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(match self.0 {
+            0 => 0,
+            s => modulus() - s,
+        })
+    }
+}
+
+impl ops::Sub for DynamicModInt {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl ops::SubAssign for DynamicModInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.0 < rhs.0 {
+            self.0 += modulus();
+        }
+        self.0 -= rhs.0;
+    }
+}
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Distribution<DynamicModInt> for Standard {
+    fn sample<R>(&self, rng: &mut R) -> DynamicModInt
+    where
+        R: Rng + ?Sized,
+    {
+        DynamicModInt(rng.gen_range(0, modulus()))
+    }
+}
+
+use std::fmt::{Debug, Error, Formatter};
+
+impl Debug for DynamicModInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("DynamicModInt(")?;
+        self.0.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+#[test]
+fn test_dynamic_mod_int() {
+    use crate::other::rand::{rand_int, random};
+
+    let check = |m: u32| {
+        set_modulus(m);
+        for _ in 0..1000 {
+            let a: DynamicModInt = random();
+            let b: DynamicModInt = random();
+            assert_eq!((a * b).0 as u64, a.0 as u64 * b.0 as u64 % m as u64);
+            assert_eq!((a + b).0 as u64, (a.0 as u64 + b.0 as u64) % m as u64);
+            assert_eq!(a - b + b, a);
+            if crate::other::gcd(b.0, m) == 1 {
+                assert_eq!(a / b * b, a);
+            }
+        }
+    };
+
+    check(1);
+    check(2);
+    check(3);
+    check(1_000_000_007);
+    check((1 << 31) - 1);
+    for _ in 0..100 {
+        check(rand_int(1..1 << 31));
+    }
+
+    set_modulus(7);
+    assert_eq!(
+        with_modulus(11, || DynamicModInt::from(-1)),
+        DynamicModInt(10)
+    );
+    assert_eq!(modulus(), 7);
+    let r = std::panic::catch_unwind(|| with_modulus(13, || panic!()));
+    assert!(r.is_err());
+    assert_eq!(modulus(), 7);
+    assert_eq!(DynamicModInt::from(-1), DynamicModInt(6));
+    assert_eq!(DynamicModInt::from(3).pow(6), DynamicModInt(1));
+    assert_eq!(DynamicModInt::from(3).inverse(), DynamicModInt(5));
+}
@@ -0,0 +1,388 @@
+/*
+
+Description
+
+T: 体
+f: T 上の K 次既約多項式 x^K - Σ_{i<K} c_i x^i
+
+拡大体 T[x] / (f) を表す。
+T = F_p のとき、位数 p^K の有限体となる。
+
+f は ExtensionModulus を実装した型によって与え、c を REDUCTION に記述する。
+既約性は検査していない。必要なら Polynomial::is_irreducible で確かめられる。
+
+乗算: Θ(K^2) 回の演算
+除算: Θ(K^3) 回の演算
+
+QuadraticExtension<M, W> は F_M(sqrt(W)) = F_M[x] / (x^2 - W) を表す。
+W は F_M の平方非剰余でなければならない。
+
+*/
+
+use crate::other::algebraic::Field;
+use crate::other::algebraic::{One, Zero};
+use crate::other::fp::ModInt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub trait ExtensionModulus<const K: usize> {
+    type Base: Field + Copy + Eq;
+    const REDUCTION: [Self::Base; K];
+}
+
+pub struct ExtensionField<E, const K: usize>
+where
+    E: ExtensionModulus<K>,
+{
+    pub coef: [E::Base; K],
+    _marker: PhantomData<fn() -> E>,
+}
+
+pub struct QuadraticModulus<const M: u32, const W: u32>;
+
+impl<const M: u32, const W: u32> ExtensionModulus<2> for QuadraticModulus<M, W> {
+    type Base = ModInt<M>;
+    const REDUCTION: [ModInt<M>; 2] = [ModInt::new(W), ModInt(0)];
+}
+
+pub type QuadraticExtension<const M: u32, const W: u32> = ExtensionField<QuadraticModulus<M, W>, 2>;
+
+impl<E, const K: usize> ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    pub fn new(coef: [E::Base; K]) -> Self {
+        Self {
+            coef,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn from_base(x: E::Base) -> Self {
+        let mut coef = [E::Base::zero(); K];
+        coef[0] = x;
+        Self::new(coef)
+    }
+
+    pub fn pow(mut self, mut exp: u64) -> Self {
+        let mut res = Self::one();
+        while exp != 0 {
+            if exp & 1 != 0 {
+                res *= self;
+            }
+            self *= self;
+            exp /= 2;
+        }
+        res
+    }
+
+    pub fn inverse(self) -> Self {
+        assert!(!self.is_zero());
+
+        // a の乗算を表す行列 [a | e_0] を掃き出す
+        let mut mat: Vec<Vec<E::Base>> = vec![vec![E::Base::zero(); K + 1]; K];
+        let mut col = self;
+        for j in 0..K {
+            for (row, &c) in mat.iter_mut().zip(&col.coef) {
+                row[j] = c;
+            }
+            col = col.mul_x();
+        }
+        mat[0][K] = E::Base::one();
+
+        for j in 0..K {
+            let p = (j..K).find(|&i| !mat[i][j].is_zero()).unwrap();
+            mat.swap(j, p);
+            let inv = E::Base::one() / mat[j][j];
+            for v in &mut mat[j][j..] {
+                *v *= inv;
+            }
+            let pivot = mat[j].clone();
+            for (i, row) in mat.iter_mut().enumerate() {
+                if i != j && !row[j].is_zero() {
+                    let c = row[j];
+                    for (v, &t) in row[j..].iter_mut().zip(&pivot[j..]) {
+                        *v -= c * t;
+                    }
+                }
+            }
+        }
+
+        let mut coef = [E::Base::zero(); K];
+        for (c, row) in coef.iter_mut().zip(&mat) {
+            *c = row[K];
+        }
+        Self::new(coef)
+    }
+
+    fn mul_x(self) -> Self {
+        let top = self.coef[K - 1];
+        let mut coef = [E::Base::zero(); K];
+        for (i, c) in coef.iter_mut().enumerate() {
+            *c = top * E::REDUCTION[i];
+            if i != 0 {
+                *c += self.coef[i - 1];
+            }
+        }
+        Self::new(coef)
+    }
+}
+
+impl<E, const K: usize> Clone for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E, const K: usize> Copy for ExtensionField<E, K> where E: ExtensionModulus<K> {}
+
+impl<E, const K: usize> PartialEq for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.coef == rhs.coef
+    }
+}
+
+impl<E, const K: usize> Eq for ExtensionField<E, K> where E: ExtensionModulus<K> {}
+
+impl<E, const K: usize, const M: u32> From<ModInt<M>> for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K, Base = ModInt<M>>,
+{
+    fn from(x: ModInt<M>) -> Self {
+        Self::from_base(x)
+    }
+}
+
+impl<E, const K: usize> Zero for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn zero() -> Self {
+        Self::new([E::Base::zero(); K])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coef.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<E, const K: usize> One for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn one() -> Self {
+        Self::from_base(E::Base::one())
+    }
+}
+
+impl<E, const K: usize> Add for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<E, const K: usize> AddAssign for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.coef.iter_mut().zip(rhs.coef) {
+            *a += b;
+        }
+    }
+}
+
+impl<E, const K: usize> Sub for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<E, const K: usize> SubAssign for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.coef.iter_mut().zip(rhs.coef) {
+            *a -= b;
+        }
+    }
+}
+
+impl<E, const K: usize> Neg for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for a in &mut self.coef {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+impl<E, const K: usize> Mul for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut prod = vec![E::Base::zero(); 2 * K - 1];
+        for (i, &a) in self.coef.iter().enumerate() {
+            for (j, &b) in rhs.coef.iter().enumerate() {
+                prod[i + j] += a * b;
+            }
+        }
+        for i in (K..2 * K - 1).rev() {
+            let c = prod[i];
+            for (j, &r) in E::REDUCTION.iter().enumerate() {
+                prod[i - K + j] += c * r;
+            }
+        }
+        let mut coef = [E::Base::zero(); K];
+        coef.copy_from_slice(&prod[..K]);
+        Self::new(coef)
+    }
+}
+
+impl<E, const K: usize> MulAssign for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<E, const K: usize> Div for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+impl<E, const K: usize> DivAssign for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl<E, const K: usize> Distribution<ExtensionField<E, K>> for Standard
+where
+    E: ExtensionModulus<K>,
+    Standard: Distribution<E::Base>,
+{
+    fn sample<R>(&self, rng: &mut R) -> ExtensionField<E, K>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut coef = [E::Base::zero(); K];
+        for c in &mut coef {
+            *c = rng.gen();
+        }
+        ExtensionField::new(coef)
+    }
+}
+
+use std::fmt::{Debug, Error, Formatter};
+
+impl<E, const K: usize> Debug for ExtensionField<E, K>
+where
+    E: ExtensionModulus<K>,
+    E::Base: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ExtensionField")?;
+        f.debug_list().entries(self.coef.iter()).finish()
+    }
+}
+
+#[test]
+fn test_extension_field() {
+    use crate::algorithm::determinant;
+    use crate::algorithm::division_free_determinant::division_free_determinant;
+    use crate::other::rand::random;
+    use crate::other::Polynomial;
+
+    {
+        // F_7[x] / (x^3 - 2)
+        struct Cubic;
+        impl ExtensionModulus<3> for Cubic {
+            type Base = ModInt<7>;
+            const REDUCTION: [ModInt<7>; 3] = [ModInt(2), ModInt(0), ModInt(0)];
+        }
+        type F = ExtensionField<Cubic, 3>;
+
+        let all: Vec<F> = (0..343)
+            .map(|i| F::new([ModInt(i % 7), ModInt(i / 7 % 7), ModInt(i / 49)]))
+            .collect();
+        for &a in &all[1..] {
+            assert_eq!(a * a.inverse(), F::one());
+            assert_eq!(a.pow(342), F::one());
+        }
+        let x = all[7];
+        assert_eq!(x * x * x, F::from(ModInt(2)));
+    }
+
+    {
+        type F = QuadraticExtension<998244353, 3>;
+
+        let i = F::new([ModInt(0), ModInt(1)]);
+        assert_eq!(i * i, F::from(ModInt(3)));
+
+        for _ in 0..1000 {
+            let a: F = random();
+            let b: F = random();
+            let c: F = random();
+            assert_eq!((a + b) * c, a * c + b * c);
+            assert_eq!(a * b, b * a);
+            if !b.is_zero() {
+                assert_eq!(a / b * b, a);
+            }
+        }
+        let a: F = random();
+        assert_eq!(a.pow(998244353u64 * 998244353 - 1), F::one());
+
+        for n in 0..6 {
+            let a: Vec<Vec<F>> = (0..n).map(|_| (0..n).map(|_| random()).collect()).collect();
+            assert_eq!(determinant(a.clone()), division_free_determinant(&a));
+        }
+
+        let p: Polynomial<F> = (0..5).map(|_| random()).collect();
+        let q: Polynomial<F> = (0..5).map(|_| random()).collect();
+        let r: F = random();
+        assert_eq!(
+            (p.clone() * q.clone()).evaluate(&r),
+            p.evaluate(&r) * q.evaluate(&r)
+        );
+    }
+}
@@ -0,0 +1,213 @@
+/*
+
+Description
+
+n: 正の 64 bit 符号なし整数
+
+factorize(n): n の素因数分解を (素数, 指数) の列として素数の昇順に返す。
+divisors(n): n の正の約数を昇順に返す。
+euler_phi(n): Euler の φ 関数
+carmichael_lambda(n): Carmichael の λ 関数
+
+時間計算量: 期待 O(n^(1/4) log(n))
+divisors は加えて O(d(n) log(d(n)))
+
+小さい素因数を試し割りで取り除いた後、
+Brent による改良を加えた Pollard の rho 法を用いる。
+乗算は 128 bit 整数を介して行っている。
+
+*/
+
+use crate::other::gcd;
+use crate::other::is_prime::is_prime;
+use crate::other::mod_pow::mod_mul;
+
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    assert_ne!(n, 0);
+    let mut primes = Vec::new();
+    for p in 2..64 {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut stack = vec![n];
+    while let Some(n) = stack.pop() {
+        if n == 1 {
+            continue;
+        }
+        if is_prime(n) {
+            primes.push(n);
+            continue;
+        }
+        let d = pollard_rho(n);
+        stack.push(d);
+        stack.push(n / d);
+    }
+    primes.sort_unstable();
+
+    let mut res: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match res.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
+
+fn pollard_rho(n: u64) -> u64 {
+    const M: u64 = 128;
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let mut y = 2;
+        let mut x = y;
+        let mut ys = y;
+        let mut q = 1;
+        let mut g = 1;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..M.min(r - k) {
+                    y = f(y);
+                    q = mod_mul(n, q, x.abs_diff(y));
+                }
+                g = gcd(q, n);
+                k += M;
+            }
+            r *= 2;
+        }
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g != 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut res = vec![1];
+    for (p, e) in factorize(n) {
+        let len = res.len();
+        let mut pk = 1;
+        for _ in 0..e {
+            pk *= p;
+            for i in 0..len {
+                res.push(res[i] * pk);
+            }
+        }
+    }
+    res.sort_unstable();
+    res
+}
+
+pub fn euler_phi(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(p, e)| p.pow(e - 1) * (p - 1))
+        .product()
+}
+
+pub fn carmichael_lambda(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(p, e)| {
+            if p == 2 && e >= 3 {
+                1 << (e - 2)
+            } else {
+                p.pow(e - 1) * (p - 1)
+            }
+        })
+        .fold(1, |l, x| l / gcd(l, x) * x)
+}
+
+#[test]
+fn test_factorize() {
+    use crate::other::mod_pow::mod_pow;
+    use crate::other::rand::rand_int;
+
+    fn naive(mut n: u64) -> Vec<(u64, u32)> {
+        let mut res = Vec::new();
+        let mut p = 2;
+        while p * p <= n {
+            let mut e = 0;
+            while n % p == 0 {
+                n /= p;
+                e += 1;
+            }
+            if e != 0 {
+                res.push((p, e));
+            }
+            p += 1;
+        }
+        if n != 1 {
+            res.push((n, 1));
+        }
+        res
+    }
+
+    for n in 1..2000 {
+        assert_eq!(factorize(n), naive(n));
+        assert_eq!(
+            divisors(n),
+            (1..=n).filter(|d| n % d == 0).collect::<Vec<_>>()
+        );
+        let phi = (1..=n).filter(|&a| gcd(a, n) == 1).count() as u64;
+        assert_eq!(euler_phi(n), phi);
+        if n <= 200 {
+            let lambda = (1..)
+                .find(|&k| (1..=n).all(|a| gcd(a, n) != 1 || mod_pow(n, a, k) == 1 % n))
+                .unwrap();
+            assert_eq!(carmichael_lambda(n), lambda);
+        }
+    }
+
+    let primes = [
+        2,
+        3,
+        998244353,
+        1_000_000_007,
+        4294967291,
+        4294967311,
+        (1 << 61) - 1,
+        18446744073709551557,
+    ];
+    for _ in 0..100 {
+        let mut n: u64 = 1;
+        let mut ans: Vec<(u64, u32)> = Vec::new();
+        for &p in &primes {
+            let mut e = 0;
+            while rand_int(0..3) == 0 {
+                match n.checked_mul(p) {
+                    Some(m) => {
+                        n = m;
+                        e += 1;
+                    }
+                    None => break,
+                }
+            }
+            if e != 0 {
+                ans.push((p, e));
+            }
+        }
+        assert_eq!(factorize(n), ans);
+    }
+    assert_eq!(
+        factorize(4294967291 * 4294967279),
+        vec![(4294967279, 1), (4294967291, 1)]
+    );
+}
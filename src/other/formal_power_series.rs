@@ -0,0 +1,192 @@
+/*
+
+Description
+
+f: 形式的冪級数 (inv は体上、それ以外は F_M 上)
+n: 求める項数
+
+f mod x^n に関する以下の演算を行う。いずれも長さ n の Polynomial を返す。
+
+inv(n): 1 / f 、f_0 ≠ 0 を仮定する。
+log(n): log(f) 、f_0 = 1 を仮定する。
+exp(n): exp(f) 、f_0 = 0 を仮定する。
+pow(k, n): f^k
+sqrt(n): g^2 = f を満たす g を一つ返す。存在しない場合 None を返す。
+
+時間計算量: 多項式乗算の計算量を M(n) として O(M(n))
+
+いずれも Newton 法により精度を倍々にして求める。
+乗算は Polynomial の Mul を用いるので、M が NTT に適した素数なら M(n) = O(n log(n)) となる。
+n < M を仮定している。
+
+*/
+
+use crate::other::algebraic::{Field, One, Zero};
+use crate::other::fp::ModInt;
+use crate::other::fp_utils::FpUtils;
+use crate::other::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Field + Clone,
+{
+    pub fn inv(&self, n: usize) -> Self {
+        assert!(!self.coef.is_empty() && !self.coef[0].is_zero());
+        let mut g: Self = (T::one() / self.coef[0].clone()).into();
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            let mut t = -(self.prefix(k) * g.clone()).truncated(k);
+            t.coef[0] += T::one() + T::one();
+            g = (g * t).truncated(k);
+        }
+        g.truncated(n)
+    }
+}
+
+impl<const M: u32> Polynomial<ModInt<M>> {
+    pub fn log(&self, n: usize) -> Self {
+        assert!(!self.coef.is_empty() && self.coef[0] == ModInt::one());
+        if n == 0 {
+            return Self::new();
+        }
+        (self.prefix(n).derivative() * self.inv(n))
+            .truncated(n - 1)
+            .integral()
+    }
+
+    pub fn exp(&self, n: usize) -> Self {
+        assert!(self.coef.first().is_none_or(|c| c.is_zero()));
+        let mut g: Self = ModInt::one().into();
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            let mut t = (self.prefix(k) - g.log(k)).truncated(k);
+            t.coef[0] += ModInt::one();
+            g = (g * t).truncated(k);
+        }
+        g.truncated(n)
+    }
+
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return Self::from(ModInt::one()).truncated(n);
+        }
+        let d = match self.coef.iter().position(|c| !c.is_zero()) {
+            Some(d) if (d as u128) * (k as u128) < n as u128 => d,
+            _ => return Self::new().truncated(n),
+        };
+        let s = d * k as usize;
+        let c = self.coef[d];
+        let g: Self = self.coef[d..].iter().map(|&x| x / c).collect();
+        let h = (g.log(n - s) * ModInt::from(k)).exp(n - s) * c.pow(k);
+        (h << s).truncated(n)
+    }
+
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let d = match self.coef.iter().position(|c| !c.is_zero()) {
+            Some(d) if d < n => d,
+            _ => return Some(Self::new().truncated(n)),
+        };
+        if d % 2 != 0 {
+            return None;
+        }
+        let c = self.coef[d];
+        let r = c.sqrt()?;
+        let g: Self = self.coef[d..].iter().map(|&x| x / c).collect();
+        let inv2 = ModInt::one() / ModInt::new(2);
+        let mut h: Self = ModInt::one().into();
+        let mut k = 1;
+        while k < n - d / 2 {
+            k *= 2;
+            h = ((h.clone() + g.prefix(k) * h.inv(k)) * inv2).truncated(k);
+        }
+        Some(((h * r) << (d / 2)).truncated(n))
+    }
+
+    pub fn integral(&self) -> Self {
+        let utils = FpUtils::<M>::new(self.coef.len());
+        let mut res = vec![ModInt::zero()];
+        res.extend(
+            self.coef
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| c * utils.inv(i + 1)),
+        );
+        res.into()
+    }
+}
+
+#[test]
+fn test_formal_power_series() {
+    use crate::other::rand::{rand_int, random};
+
+    fn test<const M: u32>(n: usize) {
+        type F<const M: u32> = Polynomial<ModInt<M>>;
+        let one = F::<M>::from(ModInt::one()).truncated(n);
+
+        let mut f: F<M> = (0..n).map(|_| random()).collect();
+        if n != 0 && f.coef[0].is_zero() {
+            f.coef[0] = ModInt::one();
+        }
+        if n != 0 {
+            assert_eq!((f.clone() * f.inv(n)).truncated(n).coef, one.coef);
+        }
+
+        let mut g = f.clone();
+        if n != 0 {
+            g.coef[0] = ModInt::one();
+            assert_eq!(g.log(n).exp(n).coef, g.coef);
+            g.coef[0] = ModInt::zero();
+            assert_eq!(g.exp(n).log(n).coef, g.coef);
+        }
+
+        let k = rand_int(0..5);
+        let mut h = f.clone();
+        for c in h.coef.iter_mut().take(rand_int(0..3)) {
+            *c = ModInt::zero();
+        }
+        let naive = (0..k).fold(one.clone(), |p, _| (p * h.clone()).truncated(n));
+        assert_eq!(h.pow(k, n).coef, naive.coef);
+        let mut k = rand_int(1 << 40..1 << 50);
+        let big = k;
+        let mut x = h.clone();
+        let mut naive = one.clone();
+        while k != 0 {
+            if k & 1 != 0 {
+                naive = (naive * x.clone()).truncated(n);
+            }
+            x = (x.clone() * x).truncated(n);
+            k /= 2;
+        }
+        assert_eq!(h.pow(big, n).coef, naive.coef);
+
+        let s = (h.clone() * h.clone()).truncated(n);
+        let r = s.sqrt(n).unwrap();
+        assert_eq!((r.clone() * r).truncated(n).coef, s.coef);
+        if n >= 2 {
+            let t = F::<M>::from(vec![ModInt::zero(), ModInt::one()]).truncated(n);
+            assert!(t.sqrt(n).is_none());
+        }
+    }
+
+    // f ≡ 0 mod x^n なら 0 が平方根
+    {
+        type F = Polynomial<ModInt<998244353>>;
+        let p = |c: &[u32]| -> F { c.iter().map(|&c| ModInt::new(c)).collect() };
+        assert_eq!(p(&[0, 0, 0, 1]).sqrt(2).unwrap().coef, p(&[0, 0]).coef);
+        assert_eq!(p(&[0, 0, 3]).sqrt(2).unwrap().coef, p(&[0, 0]).coef);
+        assert!(p(&[0, 0, 3]).sqrt(3).is_none());
+        let r = p(&[0, 0, 4, 1]).sqrt(3).unwrap();
+        assert_eq!((r.clone() * r).truncated(3).coef, p(&[0, 0, 4]).coef);
+    }
+
+    for n in 0..20 {
+        test::<998244353>(n);
+        test::<1_000_000_007>(n);
+    }
+    for _ in 0..3 {
+        test::<998244353>(rand_int(0..1000));
+    }
+    test::<1_000_000_007>(300);
+}
@@ -1,22 +1,40 @@
+/*
+
+ModInt<M>: Z / MZ
+
+M は 2^31 未満の素数を仮定している。
+除算は Fermat の小定理を用いて計算する。
+
+*/
+
+use crate::other::discrete_log::discrete_log;
+use crate::other::mod_sqrt::mod_sqrt;
+use crate::other::primitive_root::{order, primitive_root_u32};
 use std::convert::From;
 use std::iter;
 use std::ops;
 
 pub const P: u32 = 998244353;
 
+pub type Fp = ModInt<P>;
+
 #[derive(Copy, Clone, Eq, PartialEq)]
-pub struct Fp(pub u32);
-
-impl Fp {
-    pub fn pow(mut self, exp: u64) -> Fp {
-        let mut exp = if exp > P as u64 {
-            1 + (exp - 1) % P as u64
-        } else {
-            exp
-        } as u32;
-        let mut res = Fp(1);
+pub struct ModInt<const M: u32>(pub u32);
+
+impl<const M: u32> ModInt<M> {
+    pub const MOD: u32 = M;
+
+    // 加減算が u32 で溢れないための制約
+    const CHECK: () = assert!(M < 1 << 31);
+
+    pub const fn new(x: u32) -> Self {
+        Self(x % M)
+    }
+
+    pub fn pow(mut self, mut exp: u64) -> Self {
+        let mut res = Self(1);
         while exp != 0 {
-            if exp % 2 != 0 {
+            if exp & 1 != 0 {
                 res *= self;
             }
             self *= self;
@@ -24,13 +42,31 @@ impl Fp {
         }
         res
     }
+
+    pub const PRIMITIVE_ROOT: u32 = primitive_root_u32(M);
+
+    pub fn primitive_root() -> Self {
+        Self(Self::PRIMITIVE_ROOT)
+    }
+
+    pub fn order(self) -> u64 {
+        order(M.into(), self.0.into())
+    }
+
+    pub fn sqrt(self) -> Option<Self> {
+        mod_sqrt(M.into(), self.0.into()).map(|x| Self(x as u32))
+    }
+
+    pub fn log(self, base: Self) -> Option<u64> {
+        discrete_log(M.into(), base.0.into(), self.0.into())
+    }
 }
 
 use crate::other::algebraic::{One, Zero};
 
-impl Zero for Fp {
-    fn zero() -> Fp {
-        Fp(0)
+impl<const M: u32> Zero for ModInt<M> {
+    fn zero() -> Self {
+        Self(0)
     }
 
     fn is_zero(&self) -> bool {
@@ -38,18 +74,22 @@ impl Zero for Fp {
     }
 }
 
-impl One for Fp {
-    fn one() -> Fp {
-        Fp(1)
+impl<const M: u32> One for ModInt<M> {
+    fn one() -> Self {
+        Self(1)
+    }
+
+    fn convolution(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        Some(crate::algorithm::three_prime_convolution::mod_int_convolution(a, b))
     }
 }
 
 macro_rules! impl_from_int {
     ($(($ty:ty: $via:ty)),*) => {
         $(
-            impl From<$ty> for Fp {
-                fn from(x: $ty) -> Fp {
-                    Fp((x as $via).rem_euclid(P as $via) as u32)
+            impl<const M: u32> From<$ty> for ModInt<M> {
+                fn from(x: $ty) -> Self {
+                    Self((x as $via).rem_euclid(M as $via) as u32)
                 }
             }
         )*
@@ -69,107 +109,109 @@ impl_from_int!(
     (usize: u64)
 );
 
-impl iter::Product for Fp {
-    fn product<I>(iter: I) -> Fp
+impl<const M: u32> iter::Product for ModInt<M> {
+    fn product<I>(iter: I) -> Self
     where
-        I: Iterator<Item = Fp>,
+        I: Iterator<Item = Self>,
     {
-        iter.fold(Fp(1), |b, i| b * i)
+        iter.fold(Self(1), |b, i| b * i)
     }
 }
 
-impl iter::Sum for Fp {
-    fn sum<I>(iter: I) -> Fp
+impl<const M: u32> iter::Sum for ModInt<M> {
+    fn sum<I>(iter: I) -> Self
     where
-        I: Iterator<Item = Fp>,
+        I: Iterator<Item = Self>,
     {
-        iter.fold(Fp(0), |b, i| b + i)
+        iter.fold(Self(0), |b, i| b + i)
     }
 }
 
-impl ops::Add<Fp> for Fp {
-    type Output = Fp;
-    fn add(mut self, rhs: Fp) -> Fp {
+impl<const M: u32> ops::Add<Self> for ModInt<M> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
         self += rhs;
         self
     }
 }
 
-impl ops::AddAssign<Fp> for Fp {
-    fn add_assign(&mut self, rhs: Fp) {
+impl<const M: u32> ops::AddAssign<Self> for ModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        let () = Self::CHECK;
         self.0 += rhs.0;
-        if self.0 >= P {
-            self.0 -= P;
+        if self.0 >= M {
+            self.0 -= M;
         }
     }
 }
 
-impl ops::Div for Fp {
-    type Output = Fp;
-    fn div(mut self, rhs: Fp) -> Fp {
+impl<const M: u32> ops::Div for ModInt<M> {
+    type Output = Self;
+    fn div(mut self, rhs: Self) -> Self {
         assert_ne!(rhs.0, 0);
         self /= rhs;
         self
     }
 }
 
-impl ops::DivAssign for Fp {
-    fn div_assign(&mut self, rhs: Fp) {
+impl<const M: u32> ops::DivAssign for ModInt<M> {
+    fn div_assign(&mut self, rhs: Self) {
         assert_ne!(rhs.0, 0);
-        *self *= rhs.pow((P - 2).into());
+        *self *= rhs.pow((M - 2).into());
     }
 }
 
-impl ops::Mul<Fp> for Fp {
-    type Output = Fp;
-    fn mul(self, rhs: Fp) -> Fp {
-        Fp((self.0 as u64 * rhs.0 as u64 % P as u64) as u32)
+impl<const M: u32> ops::Mul<Self> for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self((self.0 as u64 * rhs.0 as u64 % M as u64) as u32)
     }
 }
 
-impl ops::Mul<usize> for Fp {
-    type Output = Fp;
-    fn mul(self, rhs: usize) -> Fp {
-        self * Fp::from(rhs)
+impl<const M: u32> ops::Mul<usize> for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: usize) -> Self {
+        self * Self::from(rhs)
     }
 }
 
-impl ops::MulAssign<Fp> for Fp {
-    fn mul_assign(&mut self, rhs: Fp) {
+impl<const M: u32> ops::MulAssign<Self> for ModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
         *self = *self * rhs;
     }
 }
 
-impl ops::Neg for Fp {
-    type Output = Fp;
-    fn neg(self) -> Fp {
-        Fp(match self.0 {
+impl<const M: u32> ops::Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(match self.0 {
             0 => 0,
-            s => P - s,
+            s => M - s,
         })
     }
 }
 
-impl ops::Sub<Fp> for Fp {
-    type Output = Fp;
-    fn sub(mut self, rhs: Fp) -> Fp {
+impl<const M: u32> ops::Sub<Self> for ModInt<M> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
         self -= rhs;
         self
     }
 }
 
-impl ops::SubAssign<Fp> for Fp {
-    fn sub_assign(&mut self, rhs: Fp) {
+impl<const M: u32> ops::SubAssign<Self> for ModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        let () = Self::CHECK;
         if self.0 < rhs.0 {
-            self.0 += P;
+            self.0 += M;
         }
         self.0 -= rhs.0;
     }
 }
 
-impl Default for Fp {
-    fn default() -> Fp {
-        Fp(0)
+impl<const M: u32> Default for ModInt<M> {
+    fn default() -> Self {
+        Self(0)
     }
 }
 
@@ -178,21 +220,63 @@ use rand::{
     Rng,
 };
 
-impl Distribution<Fp> for Standard {
-    fn sample<R>(&self, rng: &mut R) -> Fp
+impl<const M: u32> Distribution<ModInt<M>> for Standard {
+    fn sample<R>(&self, rng: &mut R) -> ModInt<M>
     where
         R: Rng + ?Sized,
     {
-        Fp(rng.gen_range(0, P))
+        ModInt(rng.gen_range(0, M))
     }
 }
 
 use std::fmt::{Debug, Error, Formatter};
 
-impl Debug for Fp {
+impl<const M: u32> Debug for ModInt<M> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str("Fp(")?;
+        f.write_str("ModInt(")?;
         self.0.fmt(f)?;
         f.write_str(")")
     }
 }
+
+#[test]
+fn test_mod_int() {
+    use crate::other::rand::random;
+
+    type F = ModInt<1_000_000_007>;
+
+    assert_eq!(Fp::from(-1), ModInt(P - 1));
+    assert_eq!(F::from(-1), ModInt(1_000_000_006));
+    assert_eq!(F::from(1_000_000_008u64), ModInt(1));
+    assert_eq!(F::new(1_000_000_009), ModInt(2));
+
+    for _ in 0..1000 {
+        let a: F = random();
+        let b: F = random();
+        assert_eq!(
+            a * b,
+            ModInt((a.0 as u64 * b.0 as u64 % F::MOD as u64) as u32)
+        );
+        if b.0 != 0 {
+            assert_eq!(a / b * b, a);
+        }
+        assert_eq!(
+            a.pow(F::MOD as u64 - 1),
+            if a.0 == 0 { ModInt(0) } else { ModInt(1) }
+        );
+        assert_eq!(a.pow(F::MOD as u64 + 1), a * a);
+        assert!((a * a).sqrt() == Some(a) || (a * a).sqrt() == Some(-a));
+    }
+
+    for _ in 0..10 {
+        let a: F = random();
+        if a.0 != 0 {
+            assert_eq!(a.pow(a.order()), ModInt(1));
+            let g = F::primitive_root();
+            assert_eq!(g.pow(a.log(g).unwrap()), a);
+        }
+    }
+
+    assert_eq!(Fp::primitive_root(), ModInt(3));
+    assert_eq!(F::primitive_root().order(), F::MOD as u64 - 1);
+}
@@ -1,38 +1,43 @@
 use crate::other::algebraic::One;
-use crate::other::{fp::P, Fp};
+use crate::other::fp::ModInt;
 
-pub struct FpUtils {
-    fact_: Vec<Fp>,
-    inv_: Vec<Fp>,
+pub struct FpUtils<const M: u32> {
+    fact_: Vec<ModInt<M>>,
+    inv_: Vec<ModInt<M>>,
 }
 
-impl FpUtils {
+impl<const M: u32> FpUtils<M> {
     pub fn new(n: usize) -> Self {
-        assert!((n as u64) < (P as u64));
-        let mut fact_ = vec![Fp::default(); n + 1];
-        fact_[0] = Fp::one();
+        assert!((n as u64) < (M as u64));
+        let mut fact_ = vec![ModInt::default(); n + 1];
+        fact_[0] = ModInt::one();
         for i in 0..n {
-            let temp = fact_[i] * Fp(i as u32 + 1);
+            let temp = fact_[i] * ModInt(i as u32 + 1);
             fact_[i + 1] = temp;
         }
-        let mut inv_ = vec![Fp::default(); n + 1];
-        inv_[n] = Fp::one() / fact_[n];
+        let mut inv_ = vec![ModInt::default(); n + 1];
+        inv_[n] = ModInt::one() / fact_[n];
         for i in (0..n).rev() {
-            let temp = inv_[i + 1] * Fp(i as u32 + 1);
+            let temp = inv_[i + 1] * ModInt(i as u32 + 1);
             inv_[i] = temp;
         }
         Self { fact_, inv_ }
     }
 
-    pub fn fact(&self, n: usize) -> Fp {
+    pub fn fact(&self, n: usize) -> ModInt<M> {
         self.fact_[n]
     }
 
-    pub fn inv_fact(&self, n: usize) -> Fp {
+    pub fn inv_fact(&self, n: usize) -> ModInt<M> {
         self.inv_[n]
     }
 
-    pub fn binom(&self, n: usize, r: usize) -> Fp {
+    pub fn inv(&self, n: usize) -> ModInt<M> {
+        assert_ne!(n, 0);
+        self.fact_[n - 1] * self.inv_[n]
+    }
+
+    pub fn binom(&self, n: usize, r: usize) -> ModInt<M> {
         assert!(r <= n);
         self.fact_[n] * self.inv_[r] * self.inv_[n - r]
     }
@@ -0,0 +1,88 @@
+/*
+
+Description
+
+n: 64 bit 符号なし整数
+
+n が素数か判定する。
+
+時間計算量: O(log(n))
+
+決定的 Miller-Rabin 素数判定法。
+7 個の基数 2, 325, 9375, 28178, 450775, 9780504, 1795265022 で
+2^64 未満の全ての整数を正しく判定できることが知られている。
+
+*/
+
+use crate::other::mod_pow::{mod_mul, mod_pow};
+
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    [2, 325, 9375, 28178, 450775, 9780504, 1795265022]
+        .iter()
+        .all(|&a| {
+            let a = a % n;
+            if a == 0 {
+                return true;
+            }
+            let mut x = mod_pow(n, a, d);
+            if x == 1 || x == n - 1 {
+                return true;
+            }
+            for _ in 1..s {
+                x = mod_mul(n, x, x);
+                if x == n - 1 {
+                    return true;
+                }
+            }
+            false
+        })
+}
+
+#[test]
+fn test_is_prime() {
+    let n = 100000;
+    let mut sieve = vec![true; n];
+    sieve[0] = false;
+    sieve[1] = false;
+    for i in 2..n {
+        if sieve[i] {
+            for j in (2 * i..n).step_by(i) {
+                sieve[j] = false;
+            }
+        }
+    }
+    for (i, &s) in sieve.iter().enumerate() {
+        assert_eq!(is_prime(i as u64), s);
+    }
+
+    for &p in &[
+        998244353,
+        1_000_000_007,
+        (1 << 61) - 1,
+        18446744073709551557,
+    ] {
+        assert!(is_prime(p));
+    }
+    for &c in &[
+        561,
+        3215031751,
+        3825123056546413051,
+        4294967297,
+        (1 << 62) - 1,
+        18446744073709551615,
+        1_000_000_007 * 998244353,
+        1_000_000_007 * 1_000_000_009,
+    ] {
+        assert!(!is_prime(c));
+    }
+}
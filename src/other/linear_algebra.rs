@@ -0,0 +1,270 @@
+/*
+
+Description
+
+T: 体
+a: T 上の n × m 行列
+b: 長さ n の T の列
+
+rref(a): a の簡約階段形と、主成分のある列の番号の列
+rank(a): a の階数
+determinant(a): a の行列式 (n = m)
+inverse(a): a の逆行列、存在しなければ None (n = m)
+kernel(a): a x = 0 を満たす x 全体の基底
+solve(a, b): a x = b を満たす x の一つと kernel(a) の組、解が無ければ None
+
+時間計算量: Θ(n m min(n, m)) 回の演算
+(inverse, solve は a に単位行列や b を連結した行列に対する計算量)
+
+いずれも Gauss-Jordan の掃き出し法による。
+a が大きさを持たない Matrix::scalar の場合は panic する。
+
+*/
+
+use crate::matrix;
+use crate::other::algebraic::{one, zero, Field};
+use crate::other::matrix::Matrix;
+
+impl<T> Matrix<T>
+where
+    T: Field + Clone,
+{
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        self.assert_sized();
+        let mut a = self.clone();
+        let n = a.row_count();
+        let m = a.col_count();
+        let mut pivots = Vec::new();
+        for c in 0..m {
+            let r = pivots.len();
+            if r == n {
+                break;
+            }
+            let p = match (r..n).find(|&i| !a[i][c].is_zero()) {
+                None => continue,
+                Some(p) => p,
+            };
+            a.swap_rows(r, p);
+            let inv = T::one() / a[r][c].clone();
+            for x in &mut a[r][c..] {
+                *x *= inv.clone();
+            }
+            let pr = a[r][c..].to_vec();
+            for i in 0..n {
+                if i == r || a[i][c].is_zero() {
+                    continue;
+                }
+                let f = a[i][c].clone();
+                for (x, p) in a[i][c..].iter_mut().zip(&pr) {
+                    *x -= f.clone() * p.clone();
+                }
+            }
+            pivots.push(c);
+        }
+        (a, pivots)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    pub fn determinant(&self) -> T {
+        self.assert_sized();
+        let n = self.row_count();
+        assert_eq!(self.col_count(), n);
+        let mut a = self.clone();
+        let mut res: T = one();
+        for c in 0..n {
+            match (c..n).find(|&r| !a[r][c].is_zero()) {
+                None => return zero(),
+                Some(r) => {
+                    if r != c {
+                        a.swap_rows(c, r);
+                        res = -res;
+                    }
+                }
+            }
+            let p = a[c][c].clone();
+            let inv = T::one() / p.clone();
+            for x in &mut a[c][c..] {
+                *x *= inv.clone();
+            }
+            res *= p;
+            let pr = a[c][c..].to_vec();
+            for i in c + 1..n {
+                let f = a[i][c].clone();
+                for (x, p) in a[i][c..].iter_mut().zip(&pr) {
+                    *x -= f.clone() * p.clone();
+                }
+            }
+        }
+        res
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        self.assert_sized();
+        let n = self.row_count();
+        assert_eq!(self.col_count(), n);
+        let mut b = matrix![T::zero(); n; 2 * n];
+        for i in 0..n {
+            b[i][..n].clone_from_slice(&self[i]);
+            b[i][n + i] = T::one();
+        }
+        let (r, pivots) = b.rref();
+        if !pivots.iter().copied().take(n).eq(0..n) {
+            return None;
+        }
+        let mut res = matrix![T::zero(); n; n];
+        for i in 0..n {
+            res[i].clone_from_slice(&r[i][n..]);
+        }
+        Some(res)
+    }
+
+    pub fn kernel(&self) -> Vec<Vec<T>> {
+        self.assert_sized();
+        let (r, pivots) = self.rref();
+        kernel_from_rref(&r, &pivots, self.col_count())
+    }
+
+    pub fn solve(&self, b: &[T]) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+        self.assert_sized();
+        let n = self.row_count();
+        let m = self.col_count();
+        assert_eq!(b.len(), n);
+        let mut c = matrix![T::zero(); n; m + 1];
+        for i in 0..n {
+            c[i][..m].clone_from_slice(&self[i]);
+            c[i][m] = b[i].clone();
+        }
+        let (r, pivots) = c.rref();
+        if pivots.last() == Some(&m) {
+            return None;
+        }
+        let mut x = vec![zero::<T>(); m];
+        for (i, &p) in pivots.iter().enumerate() {
+            x[p] = r[i][m].clone();
+        }
+        Some((x, kernel_from_rref(&r, &pivots, m)))
+    }
+}
+
+// 簡約階段形 r の最初の m 列を係数とする斉次方程式の解空間の基底
+fn kernel_from_rref<T>(r: &Matrix<T>, pivots: &[usize], m: usize) -> Vec<Vec<T>>
+where
+    T: Field + Clone,
+{
+    let mut is_pivot = vec![false; m];
+    for &p in pivots {
+        is_pivot[p] = true;
+    }
+    (0..m)
+        .filter(|&f| !is_pivot[f])
+        .map(|f| {
+            let mut x = vec![zero::<T>(); m];
+            x[f] = one();
+            for (i, &p) in pivots.iter().enumerate() {
+                x[p] = -r[i][f].clone();
+            }
+            x
+        })
+        .collect()
+}
+
+#[test]
+fn test_linear_algebra() {
+    use crate::other::fp::ModInt;
+    use crate::other::rand::{rand_int, random};
+
+    fn apply<const M: u32>(a: &Matrix<ModInt<M>>, x: &[ModInt<M>]) -> Vec<ModInt<M>> {
+        (0..a.row_count())
+            .map(|i| a[i].iter().zip(x).map(|(&a, &x)| a * x).sum())
+            .collect()
+    }
+
+    fn test<const M: u32>(n: usize, m: usize) {
+        let gen = |n: usize, m: usize| {
+            let mut a: Matrix<ModInt<M>> = matrix![ModInt::new(0); n; m];
+            for i in 0..n {
+                for j in 0..m {
+                    a[i][j] = random();
+                }
+            }
+            a
+        };
+        // 低い階数の行列も作る
+        let k = rand_int(0..n.min(m) + 1);
+        let a = if rand_int(0..2) == 0 {
+            gen(n, m)
+        } else {
+            gen(n, k) * gen(k, m)
+        };
+
+        let (r, pivots) = a.rref();
+        let rank = pivots.len();
+        assert_eq!(a.rank(), rank);
+        assert!(pivots.windows(2).all(|w| w[0] < w[1]));
+        for (i, &p) in pivots.iter().enumerate() {
+            assert!(r[i][..p].iter().all(|x| x.0 == 0));
+            for j in 0..n {
+                assert_eq!(r[j][p], ModInt::new((i == j) as u32));
+            }
+        }
+        for i in rank..n {
+            assert!(r[i].iter().all(|x| x.0 == 0));
+        }
+
+        let ker = a.kernel();
+        assert_eq!(ker.len(), m - rank);
+        for x in &ker {
+            assert!(apply(&a, x).iter().all(|x| x.0 == 0));
+        }
+
+        let x: Vec<ModInt<M>> = (0..m).map(|_| random()).collect();
+        let b = apply(&a, &x);
+        let (y, ker2) = a.solve(&b).unwrap();
+        assert_eq!(apply(&a, &y), b);
+        assert_eq!(ker2.len(), ker.len());
+        let b: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+        match a.solve(&b) {
+            Some((y, _)) => assert_eq!(apply(&a, &y), b),
+            None => assert!(rank < n),
+        }
+
+        if n == m {
+            let det = a.determinant();
+            assert_eq!(det.0 != 0, rank == n);
+            match a.inverse() {
+                Some(inv) => {
+                    assert_eq!(rank, n);
+                    assert_eq!(inv.clone() * a.clone(), Matrix::identity(n));
+                    assert_eq!(a.clone() * inv, Matrix::identity(n));
+                }
+                None => assert!(rank < n),
+            }
+            let c = gen(n, n);
+            assert_eq!((a.clone() * c.clone()).determinant(), det * c.determinant());
+        }
+    }
+
+    // 大きさを持たないスカラー行列は拒否する
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    let s = Matrix::scalar(ModInt::<5>(2));
+    fn rejects<R>(f: impl FnOnce() -> R) -> bool {
+        catch_unwind(AssertUnwindSafe(f)).is_err()
+    }
+    assert!(rejects(|| s.rref()));
+    assert!(rejects(|| s.rank()));
+    assert!(rejects(|| s.determinant()));
+    assert!(rejects(|| s.inverse()));
+    assert!(rejects(|| s.kernel()));
+    assert!(rejects(|| s.solve(&[])));
+
+    for _ in 0..300 {
+        let (n, m) = (rand_int(0..7), rand_int(0..7));
+        test::<998244353>(n, m);
+        test::<2>(n, m);
+        test::<5>(n, m);
+        test::<5>(n, n);
+    }
+}
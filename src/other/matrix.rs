@@ -1,12 +1,50 @@
-#[derive(Clone, Eq, PartialEq)]
+/*
+
+Reference
+
+[1] Strassen, V. (1969).
+    Gaussian elimination is not optimal.
+    Numerische Mathematik, 13(4), 354-356.
+
+
+Description
+
+T: 半環
+a: T 上の n × m 行列
+
+行列同士の +, -, * と単項 -、スカラー倍 a * c を、値と参照の両方に対して提供する。
+scalar(c) はサイズを持たないスカラー行列 c I で、演算の相手の正方行列と同じ大きさとして扱う。
+ただし c = 0 のときは任意の形の零行列として扱う。
+Zero::zero() は scalar(0)、One::one() は scalar(1) を返すので、
+algorithm::pow や Multiplicative をそのまま用いることができる。
+scalar(c) は scalar(c) とのみ等しく、大きさを持つ行列とは等しくない。
+scalar(c) に対して添字アクセスや row_count などの大きさを必要とする操作を行うと panic する。
+
+pow(a, k): a^k (n = m)
+strassen(a, b): Strassen のアルゴリズムによる a b (T は環)
+
+時間計算量
+a * b: Θ(n m k) (b は m × k 行列)
+pow: Θ(n^3 log(k))
+strassen: O(N^log_2(7)) (N = max(n, m, k))
+
+strassen はいずれかの辺が STRASSEN_THRESHOLD 以下になると素朴な乗算に切り替える。
+
+*/
+
+#[derive(Clone)]
 pub struct Matrix<T> {
     n: usize,
     m: usize,
     a: Vec<T>,
+    // Some(c) のとき、サイズを持たない c I を表す
+    scalar: Option<T>,
 }
 
-use crate::other::algebraic::Semiring;
-use std::ops::{Index, IndexMut, Mul};
+use crate::other::algebraic::{Abelian, CommutativeMonoid, Group, One, Ring, Semiring, Zero};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+const STRASSEN_THRESHOLD: usize = 64;
 
 pub fn from_elem<T>(elem: T, n: usize, m: usize) -> Matrix<T>
 where
@@ -16,11 +54,17 @@ where
         n,
         m,
         a: vec![elem; n * m],
+        scalar: None,
     }
 }
 
 pub fn new_internal<T>(n: usize, m: usize, a: Vec<T>) -> Matrix<T> {
-    Matrix { n, m, a }
+    Matrix {
+        n,
+        m,
+        a,
+        scalar: None,
+    }
 }
 
 #[macro_export]
@@ -55,30 +99,61 @@ macro_rules! matrix {
 }
 
 impl<T> Matrix<T> {
+    pub fn scalar(c: T) -> Self {
+        Self {
+            n: 0,
+            m: 0,
+            a: Vec::new(),
+            scalar: Some(c),
+        }
+    }
+
     pub fn inner(&self) -> impl Iterator<Item = &T> {
+        self.assert_sized();
         self.a.iter()
     }
 
     pub fn row_count(&self) -> usize {
+        self.assert_sized();
         self.n
     }
 
     pub fn col_count(&self) -> usize {
+        self.assert_sized();
         self.m
     }
 
+    // 大きさを持つ行列に対してのみ意味を持つ操作で呼ぶ
+    pub(crate) fn assert_sized(&self) {
+        assert!(self.scalar.is_none(), "scalar matrix has no size");
+    }
+
     pub fn map<B, F>(self, f: F) -> Matrix<B>
     where
         F: FnMut(T) -> B,
     {
+        let mut f = f;
         Matrix {
             n: self.n,
             m: self.m,
-            a: self.a.into_iter().map(f).collect::<Vec<_>>(),
+            a: self.a.into_iter().map(&mut f).collect::<Vec<_>>(),
+            scalar: self.scalar.map(f),
+        }
+    }
+
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        self.assert_sized();
+        if i != j {
+            for k in 0..self.m {
+                self.a.swap(self.m * i + k, self.m * j + k);
+            }
         }
     }
 
     pub fn transpose(self) -> Self {
+        if self.scalar.is_some() {
+            return self;
+        }
         let mut t = Vec::with_capacity(self.m);
         for _ in 0..self.m {
             t.push(Vec::with_capacity(self.n));
@@ -95,6 +170,7 @@ impl<T> Matrix<T> {
             n: self.m,
             m: self.n,
             a: t.into_iter().flatten().collect(),
+            scalar: None,
         }
     }
 }
@@ -110,11 +186,95 @@ where
         }
         res
     }
+
+    pub fn pow(mut self, mut exp: u64) -> Self {
+        assert_eq!(self.n, self.m);
+        let mut res = match self.scalar {
+            None => Self::identity(self.n),
+            Some(_) => Self::one(),
+        };
+        while exp != 0 {
+            if !exp.is_multiple_of(2) {
+                res = &res * &self;
+            }
+            self = &self * &self;
+            exp /= 2;
+        }
+        res
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Ring + Clone,
+{
+    pub fn strassen(&self, rhs: &Self) -> Self {
+        if self.scalar.is_some() || rhs.scalar.is_some() {
+            return self * rhs;
+        }
+        assert_eq!(self.m, rhs.n);
+        let (n, m, k) = (self.n, self.m, rhs.m);
+        if n.min(m).min(k) <= STRASSEN_THRESHOLD {
+            return self * rhs;
+        }
+        let (h, w, l) = (n.div_ceil(2), m.div_ceil(2), k.div_ceil(2));
+        let a = |i: usize, j: usize| self.block(i * h, j * w, h, w);
+        let b = |i: usize, j: usize| rhs.block(i * w, j * l, w, l);
+        let (a11, a12, a21, a22) = (a(0, 0), a(0, 1), a(1, 0), a(1, 1));
+        let (b11, b12, b21, b22) = (b(0, 0), b(0, 1), b(1, 0), b(1, 1));
+        let m1 = (&a11 + &a22).strassen(&(&b11 + &b22));
+        let m2 = (&a21 + &a22).strassen(&b11);
+        let m3 = a11.strassen(&(&b12 - &b22));
+        let m4 = a22.strassen(&(&b21 - &b11));
+        let m5 = (&a11 + &a12).strassen(&b22);
+        let m6 = (a21 - a11).strassen(&(b11 + b12));
+        let m7 = (a12 - a22).strassen(&(b21 + b22));
+        let c11 = &(&m1 + &m4) - &(&m5 - &m7);
+        let c12 = &m3 + &m5;
+        let c21 = &m2 + &m4;
+        let c22 = (m1 - m2) + (m3 + m6);
+
+        let mut res = matrix![T::zero(); n; k];
+        for (bi, bj, c) in [(0, 0, c11), (0, 1, c12), (1, 0, c21), (1, 1, c22)] {
+            let cols = l.min(k - bj * l);
+            for i in 0..h.min(n - bi * h) {
+                res[bi * h + i][bj * l..bj * l + cols].clone_from_slice(&c[i][..cols]);
+            }
+        }
+        res
+    }
+
+    // (r, c) から始まる h × w の部分行列、はみ出した部分は 0 で埋める
+    fn block(&self, r: usize, c: usize, h: usize, w: usize) -> Self {
+        let mut res = matrix![T::zero(); h; w];
+        let cols = w.min(self.m - c);
+        for i in 0..h.min(self.n - r) {
+            res[i][..cols].clone_from_slice(&self[r + i][c..c + cols]);
+        }
+        res
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(a: Vec<Vec<T>>) -> Self {
+        let n = a.len();
+        let m = a.first().map_or(0, |r| r.len());
+        for r in &a {
+            assert_eq!(r.len(), m, "all rows must have the same length");
+        }
+        Self {
+            n,
+            m,
+            a: a.into_iter().flatten().collect(),
+            scalar: None,
+        }
+    }
 }
 
 impl<T> Index<usize> for Matrix<T> {
     type Output = [T];
     fn index(&self, index: usize) -> &[T] {
+        self.assert_sized();
         let r = self.m * index;
         &self.a[r..r + self.m]
     }
@@ -122,17 +282,188 @@ impl<T> Index<usize> for Matrix<T> {
 
 impl<T> IndexMut<usize> for Matrix<T> {
     fn index_mut(&mut self, index: usize) -> &mut [T] {
+        self.assert_sized();
         let r = self.m * index;
         &mut self.a[r..r + self.m]
     }
 }
 
+// 正方行列 a に c I を足す
+fn add_diagonal<T>(a: &mut Matrix<T>, c: &T)
+where
+    T: CommutativeMonoid + Clone,
+{
+    if c.is_zero() {
+        return;
+    }
+    assert_eq!(a.n, a.m);
+    for i in 0..a.n {
+        a[i][i] += c.clone();
+    }
+}
+
+impl<T> Add for Matrix<T>
+where
+    T: CommutativeMonoid + Clone,
+{
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T> Add for &Matrix<T>
+where
+    T: CommutativeMonoid + Clone,
+{
+    type Output = Matrix<T>;
+    fn add(self, rhs: Self) -> Matrix<T> {
+        if self.scalar.is_some() || rhs.scalar.is_some() {
+            return self.clone() + rhs.clone();
+        }
+        assert_eq!((self.n, self.m), (rhs.n, rhs.m));
+        Matrix {
+            n: self.n,
+            m: self.m,
+            a: self
+                .a
+                .iter()
+                .zip(&rhs.a)
+                .map(|(a, b)| a.clone() + b.clone())
+                .collect(),
+            scalar: None,
+        }
+    }
+}
+
+impl<T> AddAssign for Matrix<T>
+where
+    T: CommutativeMonoid + Clone,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        match (self.scalar.take(), rhs.scalar) {
+            (Some(c), Some(d)) => self.scalar = Some(c + d),
+            (None, Some(d)) => add_diagonal(self, &d),
+            (Some(c), None) => {
+                *self = Matrix {
+                    scalar: None,
+                    ..rhs
+                };
+                add_diagonal(self, &c);
+            }
+            (None, None) => {
+                assert_eq!((self.n, self.m), (rhs.n, rhs.m));
+                for (a, b) in self.a.iter_mut().zip(rhs.a) {
+                    *a += b;
+                }
+            }
+        }
+    }
+}
+
+impl<T> Neg for Matrix<T>
+where
+    T: Group,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+impl<T> Neg for &Matrix<T>
+where
+    T: Group + Clone,
+{
+    type Output = Matrix<T>;
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
+
+impl<T> Sub for Matrix<T>
+where
+    T: Abelian + Clone,
+{
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T> Sub for &Matrix<T>
+where
+    T: Abelian + Clone,
+{
+    type Output = Matrix<T>;
+    fn sub(self, rhs: Self) -> Matrix<T> {
+        if self.scalar.is_some() || rhs.scalar.is_some() {
+            return self.clone() - rhs.clone();
+        }
+        assert_eq!((self.n, self.m), (rhs.n, rhs.m));
+        Matrix {
+            n: self.n,
+            m: self.m,
+            a: self
+                .a
+                .iter()
+                .zip(&rhs.a)
+                .map(|(a, b)| a.clone() - b.clone())
+                .collect(),
+            scalar: None,
+        }
+    }
+}
+
+impl<T> SubAssign for Matrix<T>
+where
+    T: Abelian + Clone,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        match (self.scalar.take(), rhs.scalar) {
+            (Some(c), Some(d)) => self.scalar = Some(c - d),
+            (None, Some(d)) => add_diagonal(self, &-d),
+            (Some(c), None) => {
+                *self = -Matrix {
+                    scalar: None,
+                    ..rhs
+                };
+                add_diagonal(self, &c);
+            }
+            (None, None) => {
+                assert_eq!((self.n, self.m), (rhs.n, rhs.m));
+                for (a, b) in self.a.iter_mut().zip(rhs.a) {
+                    *a -= b;
+                }
+            }
+        }
+    }
+}
+
 impl<T> Mul for Matrix<T>
 where
     T: Semiring + Clone,
 {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl<T> Mul for &Matrix<T>
+where
+    T: Semiring + Clone,
+{
+    type Output = Matrix<T>;
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        match (&self.scalar, &rhs.scalar) {
+            (Some(c), Some(d)) => return Matrix::scalar(c.clone() * d.clone()),
+            (Some(c), None) => return rhs.clone().map(|a| c.clone() * a),
+            (None, Some(d)) => return self * d.clone(),
+            (None, None) => {}
+        }
         assert_eq!(self.m, rhs.n);
         let mut res = matrix![T::zero(); self.n; rhs.m];
         for i in 0..self.n {
@@ -149,6 +480,64 @@ where
     }
 }
 
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Semiring + Clone,
+{
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        self.map(|a| a * rhs.clone())
+    }
+}
+
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Semiring + Clone,
+{
+    type Output = Matrix<T>;
+    fn mul(self, rhs: T) -> Matrix<T> {
+        self.clone() * rhs
+    }
+}
+
+impl<T> Zero for Matrix<T>
+where
+    T: CommutativeMonoid + Clone,
+{
+    fn zero() -> Self {
+        Self::scalar(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.a.iter().chain(&self.scalar).all(T::is_zero)
+    }
+}
+
+impl<T> One for Matrix<T>
+where
+    T: Semiring + Clone,
+{
+    fn one() -> Self {
+        Self::scalar(T::one())
+    }
+}
+
+// スカラー行列はスカラー行列とのみ等しくなりうる
+impl<T> PartialEq for Matrix<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.scalar, &other.scalar) {
+            (Some(c), Some(d)) => c == d,
+            (None, None) => self.n == other.n && self.m == other.m && self.a == other.a,
+            _ => false,
+        }
+    }
+}
+
+impl<T> Eq for Matrix<T> where T: Eq {}
+
 use std::fmt::{Debug, Error, Formatter};
 
 impl<T> Debug for Matrix<T>
@@ -157,7 +546,9 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         f.write_str("Matrix ")?;
-        if self.n == 0 {
+        if let Some(c) = &self.scalar {
+            f.write_fmt(format_args!("[{:?} I]", c))
+        } else if self.n == 0 {
             f.write_fmt(format_args!("[0 × {}]", self.m))
         } else if self.m == 0 {
             f.write_fmt(format_args!("[{} × 0]", self.n))
@@ -168,3 +559,133 @@ where
         }
     }
 }
+
+#[test]
+fn test_matrix() {
+    use crate::algorithm::pow;
+    use crate::other::fp::Fp;
+    use crate::other::multiplicative::Multiplicative;
+    use crate::other::rand::{rand_int, random};
+
+    let gen = |n: usize, m: usize| {
+        let mut a: Matrix<Fp> = matrix![Fp::new(0); n; m];
+        for i in 0..n {
+            for j in 0..m {
+                a[i][j] = random();
+            }
+        }
+        a
+    };
+    let naive_mul = |a: &Matrix<Fp>, b: &Matrix<Fp>| {
+        let mut c = matrix![Fp::new(0); a.row_count(); b.col_count()];
+        for i in 0..a.row_count() {
+            for j in 0..b.col_count() {
+                for k in 0..a.col_count() {
+                    c[i][j] += a[i][k] * b[k][j];
+                }
+            }
+        }
+        c
+    };
+
+    for _ in 0..100 {
+        let (n, m, k) = (rand_int(1..8), rand_int(1..8), rand_int(1..8));
+        let (a, b, c) = (gen(n, m), gen(n, m), gen(m, k));
+        let x: Fp = random();
+        for i in 0..n {
+            for j in 0..m {
+                assert_eq!((&a + &b)[i][j], a[i][j] + b[i][j]);
+                assert_eq!((&a - &b)[i][j], a[i][j] - b[i][j]);
+                assert_eq!((-&a)[i][j], -a[i][j]);
+                assert_eq!((&a * x)[i][j], a[i][j] * x);
+            }
+        }
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+        assert_eq!(a.clone() - b.clone(), &a - &b);
+        assert_eq!(-a.clone(), -&a);
+        assert_eq!(a.clone() * x, &a * x);
+        assert_eq!(&a * &c, naive_mul(&a, &c));
+        assert_eq!(a.clone() * c.clone(), naive_mul(&a, &c));
+        assert!((&a - &a).is_zero());
+
+        assert_eq!(&a + &Matrix::zero(), a);
+        assert_eq!(&Matrix::zero() - &a, -&a);
+        assert_eq!(&Matrix::one() * &a, a);
+        assert_eq!(&a * &Matrix::one(), a);
+        assert_eq!(&Matrix::zero() * &a, matrix![Fp::new(0); n; m]);
+        assert_eq!(&a * &Matrix::zero(), matrix![Fp::new(0); n; m]);
+        assert_eq!((&Matrix::scalar(x) * &a), &a * x);
+        assert_ne!(Matrix::<Fp>::zero(), Matrix::one());
+
+        // c I は正方行列に対角成分として足される
+        let s = gen(n, n);
+        let mut t = s.clone();
+        for i in 0..n {
+            t[i][i] += x;
+        }
+        assert_eq!(&s + &Matrix::scalar(x), t);
+        assert_eq!(Matrix::scalar(x) + s.clone(), t);
+        assert_eq!(&t - &Matrix::scalar(x), s);
+        assert_eq!(Matrix::scalar(x) - s.clone(), -(&s - &Matrix::scalar(x)));
+        assert_eq!(
+            &Matrix::scalar(x) * &Matrix::identity(n),
+            Matrix::identity(n) * x
+        );
+
+        let s = gen(n, n);
+        let e = rand_int(0..20);
+        let mut naive = Matrix::identity(n);
+        for _ in 0..e {
+            naive = naive_mul(&naive, &s);
+        }
+        assert_eq!(s.clone().pow(e), naive);
+        // e = 0 のときは大きさを持たない単位行列や零行列が返る
+        let p = pow(Multiplicative(s.clone()), e).0;
+        assert_eq!(&p * &Matrix::identity(n), naive);
+        let p = pow(s.clone(), e);
+        assert_eq!(p + matrix![Fp::new(0); n; n], &s * Fp::from(e as u32));
+    }
+
+    // 大きさを必要とする操作はスカラー行列を拒否する
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    fn rejects<R>(f: impl FnOnce() -> R) -> bool {
+        catch_unwind(AssertUnwindSafe(f)).is_err()
+    }
+    let one = Matrix::<Fp>::one();
+    assert!(rejects(|| one[0][0]));
+    assert!(rejects(|| one.row_count()));
+    assert!(rejects(|| one.col_count()));
+    assert!(rejects(|| one.inner().count()));
+    assert!(rejects(|| one.clone().swap_rows(0, 0)));
+    assert!(rejects(|| one.clone()[0][0] = Fp::new(1)));
+
+    // スカラー行列との比較は推移的でなければならない
+    let z = |n: usize, m: usize| matrix![Fp::new(0); n; m];
+    assert_ne!(z(2, 3), Matrix::zero());
+    assert_ne!(Matrix::zero(), z(3, 2));
+    assert_ne!(z(2, 3), z(3, 2));
+    assert_ne!(Matrix::identity(0), Matrix::scalar(Fp::new(2)));
+    assert_ne!(Matrix::identity(0), Matrix::scalar(Fp::new(3)));
+    assert_ne!(Matrix::scalar(Fp::new(2)), Matrix::scalar(Fp::new(3)));
+    assert_eq!(Matrix::scalar(Fp::new(2)), Matrix::scalar(Fp::new(2)));
+    assert!(z(2, 3).is_zero() && Matrix::<Fp>::zero().is_zero());
+
+    for _ in 0..3 {
+        let (n, m, k) = (rand_int(1..200), rand_int(1..200), rand_int(1..200));
+        let (a, b) = (gen(n, m), gen(m, k));
+        assert_eq!(a.strassen(&b), &a * &b);
+    }
+    let (a, b) = (gen(150, 150), gen(150, 150));
+    assert_eq!(a.strassen(&b), &a * &b);
+
+    // 3 段再帰し、各段で奇数の辺を 0 で埋める
+    // (517, 259, 521) -> (259, 130, 261) -> (130, 65, 131) -> (65, 33, 66)
+    // 大きいので Freivalds のアルゴリズムで確かめる
+    let (a, b) = (gen(517, 259), gen(259, 521));
+    let c = a.strassen(&b);
+    assert_eq!((c.row_count(), c.col_count()), (517, 521));
+    for _ in 0..3 {
+        let x = gen(521, 1);
+        assert_eq!(&c * &x, &a * &(&b * &x));
+    }
+}
@@ -1,6 +1,12 @@
 use std::mem::swap;
 use std::num::Wrapping;
 
+// u64 上の剰余演算の補助関数は個別のモジュールにあり、ここからも使えるようにする
+pub use crate::other::discrete_log::discrete_log;
+pub use crate::other::mod_pow::{mod_mul, mod_pow};
+pub use crate::other::mod_sqrt::mod_sqrt;
+pub use crate::other::primitive_root::{order, primitive_root};
+
 pub fn mod_inv_binary_gcd(m: u64, a: u64) -> Option<u64> {
     assert!(m != 0);
     assert!(m <= 1 << 63);
@@ -58,6 +64,17 @@ fn mod_halve(m: u64, a: &mut u64) {
     *a /= 2;
 }
 
+#[test]
+fn test_mod_inv_reexports() {
+    let p = 998244353;
+    assert_eq!(mod_mul(p, p - 1, p - 1), 1);
+    assert_eq!(mod_pow(p, 3, p - 1), 1);
+    assert_eq!(mod_sqrt(p, 4), Some(2));
+    assert_eq!(primitive_root(p), 3);
+    assert_eq!(order(p, p - 1), 2);
+    assert_eq!(discrete_log(p, 3, 9), Some(2));
+}
+
 #[test]
 fn test_mod_inv_binary_gcd() {
     use crate::other::gcd;
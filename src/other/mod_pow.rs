@@ -0,0 +1,48 @@
+/*
+
+Description
+
+m: 正整数
+a, b: 整数
+
+mod_mul(m, a, b): a b mod m
+mod_pow(m, a, exp): a^exp mod m 、ただし m = 1 のときは 0
+
+時間計算量
+mod_mul: O(1)
+mod_pow: O(log(exp))
+
+u128 で積をとってから m で割る。
+
+*/
+
+pub fn mod_mul(m: u64, a: u64, b: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+pub fn mod_pow(m: u64, mut a: u64, mut exp: u64) -> u64 {
+    let mut res = 1 % m;
+    a %= m;
+    while exp != 0 {
+        if exp & 1 != 0 {
+            res = mod_mul(m, res, a);
+        }
+        a = mod_mul(m, a, a);
+        exp /= 2;
+    }
+    res
+}
+
+#[test]
+fn test_mod_pow() {
+    use crate::other::rand::rand_int;
+
+    for _ in 0..1000 {
+        let m = rand_int(1..1000);
+        let a = rand_int(0..u64::MAX);
+        let exp = rand_int(0..100);
+        let naive = (0..exp).fold(1 % m, |r, _| r * (a % m) % m);
+        assert_eq!(mod_pow(m, a, exp), naive);
+    }
+    assert_eq!(mod_pow(u64::MAX, u64::MAX - 1, 3), u64::MAX - 1);
+}
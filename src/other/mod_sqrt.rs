@@ -0,0 +1,85 @@
+/*
+
+Description
+
+p: 素数
+a: 整数
+
+x^2 ≡ a (mod p) を満たす x を計算する。
+解が存在しない場合 None を返す。
+解が 2 つ存在する場合は小さい方を返す。
+
+時間計算量: 期待 O(log(p)^2)
+
+Tonelli-Shanks のアルゴリズム。
+平方非剰余はランダムに選ぶ。
+
+*/
+
+use crate::other::mod_pow::{mod_mul, mod_pow};
+
+pub fn mod_sqrt(p: u64, a: u64) -> Option<u64> {
+    use crate::other::rand::rand_int;
+
+    let a = a % p;
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+    if mod_pow(p, a, (p - 1) / 2) != 1 {
+        return None;
+    }
+
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = loop {
+        let z = rand_int(2..p);
+        if mod_pow(p, z, (p - 1) / 2) == p - 1 {
+            break z;
+        }
+    };
+
+    let mut m = s;
+    let mut c = mod_pow(p, z, q);
+    let mut t = mod_pow(p, a, q);
+    let mut r = mod_pow(p, a, q.div_ceil(2));
+    while t != 1 {
+        let mut i = 0;
+        let mut u = t;
+        while u != 1 {
+            u = mod_mul(p, u, u);
+            i += 1;
+        }
+        let b = mod_pow(p, c, 1 << (m - i - 1));
+        r = mod_mul(p, r, b);
+        c = mod_mul(p, b, b);
+        t = mod_mul(p, t, c);
+        m = i;
+    }
+
+    Some(r.min(p - r))
+}
+
+#[test]
+fn test_mod_sqrt() {
+    use crate::other::rand::rand_int;
+
+    for p in (2..1000u64).filter(|&p| (2..p).all(|d| p % d != 0)) {
+        for a in 0..p {
+            let naive = (0..p).find(|&x| x * x % p == a);
+            assert_eq!(mod_sqrt(p, a), naive);
+        }
+    }
+
+    for &p in &[
+        998244353,
+        1_000_000_007,
+        (1 << 61) - 1,
+        18446744073709551557,
+    ] {
+        for _ in 0..1000 {
+            let x = rand_int(0..p);
+            let a = mod_mul(p, x, x);
+            assert_eq!(mod_sqrt(p, a), Some(x.min(p - x)));
+        }
+    }
+}
@@ -0,0 +1,275 @@
+/*
+
+Description
+
+Montgomery 表現を用いた Z / MZ
+
+x を x 2^32 mod M として保持する。
+乗算は Montgomery reduction により除算を用いずに計算する。
+公開されている演算は ModInt<M> と同一の結果を返す。
+
+M は 2^31 未満の奇素数を仮定している。
+
+*/
+
+use crate::other::algebraic::{One, Zero};
+use crate::other::fp::{ModInt, P};
+use std::convert::From;
+use std::iter;
+use std::ops;
+
+pub type MontgomeryFp = MontgomeryModInt<P>;
+
+#[derive(Copy, Clone, Eq, PartialEq, Default)]
+pub struct MontgomeryModInt<const M: u32>(u32);
+
+impl<const M: u32> MontgomeryModInt<M> {
+    pub const MOD: u32 = M;
+
+    // -M^{-1} mod 2^32
+    const NEG_INV: u32 = {
+        assert!(M % 2 == 1 && M < 1 << 31);
+        let mut inv: u32 = M;
+        let mut i = 0;
+        while i < 4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(M.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    };
+
+    // 2^64 mod M
+    const R2: u32 = ((1u128 << 64) % M as u128) as u32;
+
+    fn reduce(t: u64) -> u32 {
+        let m = (t as u32).wrapping_mul(Self::NEG_INV);
+        let u = ((t + m as u64 * M as u64) >> 32) as u32;
+        if u >= M {
+            u - M
+        } else {
+            u
+        }
+    }
+
+    pub fn new(x: u32) -> Self {
+        Self(Self::reduce((x % M) as u64 * Self::R2 as u64))
+    }
+
+    pub fn value(self) -> u32 {
+        Self::reduce(self.0 as u64)
+    }
+
+    pub fn pow(mut self, mut exp: u64) -> Self {
+        let mut res = Self::one();
+        while exp != 0 {
+            if exp & 1 != 0 {
+                res *= self;
+            }
+            self *= self;
+            exp /= 2;
+        }
+        res
+    }
+}
+
+impl<const M: u32> From<ModInt<M>> for MontgomeryModInt<M> {
+    fn from(x: ModInt<M>) -> Self {
+        Self::new(x.0)
+    }
+}
+
+impl<const M: u32> From<MontgomeryModInt<M>> for ModInt<M> {
+    fn from(x: MontgomeryModInt<M>) -> Self {
+        ModInt(x.value())
+    }
+}
+
+impl<const M: u32> Zero for MontgomeryModInt<M> {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const M: u32> One for MontgomeryModInt<M> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn convolution(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        use crate::algorithm::three_prime_convolution::mod_convolution;
+        let a: Vec<u32> = a.iter().map(|x| x.value()).collect();
+        let b: Vec<u32> = b.iter().map(|x| x.value()).collect();
+        Some(
+            mod_convolution(&a, &b, M)
+                .into_iter()
+                .map(Self::new)
+                .collect(),
+        )
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl<const M: u32> From<$ty> for MontgomeryModInt<M> {
+                fn from(x: $ty) -> Self {
+                    ModInt::<M>::from(x).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+impl<const M: u32> iter::Product for MontgomeryModInt<M> {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::one(), |b, i| b * i)
+    }
+}
+
+impl<const M: u32> iter::Sum for MontgomeryModInt<M> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self(0), |b, i| b + i)
+    }
+}
+
+impl<const M: u32> ops::Add for MontgomeryModInt<M> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<const M: u32> ops::AddAssign for MontgomeryModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+        if self.0 >= M {
+            self.0 -= M;
+        }
+    }
+}
+
+impl<const M: u32> ops::Div for MontgomeryModInt<M> {
+    type Output = Self;
+    fn div(mut self, rhs: Self) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+impl<const M: u32> ops::DivAssign for MontgomeryModInt<M> {
+    fn div_assign(&mut self, rhs: Self) {
+        assert_ne!(rhs.0, 0);
+        *self *= rhs.pow((M - 2).into());
+    }
+}
+
+impl<const M: u32> ops::Mul for MontgomeryModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(Self::reduce(self.0 as u64 * rhs.0 as u64))
+    }
+}
+
+impl<const M: u32> ops::MulAssign for MontgomeryModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: u32> ops::Neg for MontgomeryModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(match self.0 {
+            0 => 0,
+            s => M - s,
+        })
+    }
+}
+
+impl<const M: u32> ops::Sub for MontgomeryModInt<M> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const M: u32> ops::SubAssign for MontgomeryModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.0 < rhs.0 {
+            self.0 += M;
+        }
+        self.0 -= rhs.0;
+    }
+}
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl<const M: u32> Distribution<MontgomeryModInt<M>> for Standard {
+    fn sample<R>(&self, rng: &mut R) -> MontgomeryModInt<M>
+    where
+        R: Rng + ?Sized,
+    {
+        MontgomeryModInt::new(rng.gen_range(0, M))
+    }
+}
+
+use std::fmt::{Debug, Error, Formatter};
+
+impl<const M: u32> Debug for MontgomeryModInt<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("MontgomeryModInt(")?;
+        self.value().fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+#[test]
+fn test_montgomery_mod_int() {
+    use crate::other::rand::{rand_int, random};
+
+    fn test<const M: u32>() {
+        type Mont<const M: u32> = MontgomeryModInt<M>;
+
+        for x in (0..M.min(10)).chain(M.saturating_sub(10)..M) {
+            assert_eq!(Mont::<M>::new(x).value(), x);
+        }
+        assert_eq!(Mont::<M>::from(-1).value(), M - 1);
+
+        for _ in 0..10000 {
+            let a: ModInt<M> = random();
+            let b: ModInt<M> = random();
+            let (ma, mb) = (Mont::from(a), Mont::from(b));
+            assert_eq!(ModInt::from(ma + mb), a + b);
+            assert_eq!(ModInt::from(ma - mb), a - b);
+            assert_eq!(ModInt::from(ma * mb), a * b);
+            assert_eq!(ModInt::from(-ma), -a);
+            if b.0 != 0 {
+                assert_eq!(ModInt::from(ma / mb), a / b);
+            }
+            let e = rand_int(0..1 << 40);
+            assert_eq!(ModInt::from(ma.pow(e)), a.pow(e));
+        }
+    }
+
+    test::<998244353>();
+    test::<1_000_000_007>();
+    test::<3>();
+    test::<{ (1 << 31) - 1 }>();
+}
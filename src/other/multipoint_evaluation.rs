@@ -0,0 +1,143 @@
+/*
+
+Description
+
+T: 体
+f: T 上の多項式
+x: 長さ n の T の列
+y: 長さ n の T の列
+
+multipoint_evaluate(f, x): (f(x_0), ..., f(x_{n-1}))
+interpolate(x, y): f(x_i) = y_i を満たす n - 1 次以下の多項式 f
+                   x の要素は相異なる必要がある。
+
+時間計算量: O(M(n + deg(f)) log(n))
+ただし M(n) は多項式乗算の計算量
+
+Π (X - x_i) の部分積を二分木状に並べた subproduct tree を用いる。
+多点評価は根から順に剰余をとっていく。
+補間は Lagrange 補間の重み y_i / Π_{j≠i} (x_i - x_j) を
+Π (X - x_i) の微分の多点評価で求め、葉から順に足し合わせる。
+
+*/
+
+use crate::other::algebraic::{one, Field};
+use crate::other::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Field + Clone,
+{
+    pub fn multipoint_evaluate(&self, x: &[T]) -> Vec<T> {
+        if x.is_empty() {
+            return Vec::new();
+        }
+        let tree = SubproductTree::new(x);
+        let mut res = Vec::with_capacity(x.len());
+        tree.evaluate(1, 0, x.len(), self.div_rem(&tree.node[1]).1, &mut res);
+        res
+    }
+
+    pub fn interpolate(x: &[T], y: &[T]) -> Self {
+        assert_eq!(x.len(), y.len());
+        if x.is_empty() {
+            return Self::new();
+        }
+        let tree = SubproductTree::new(x);
+        let d = tree.node[1].derivative();
+        let mut w = Vec::with_capacity(x.len());
+        tree.evaluate(1, 0, x.len(), d.div_rem(&tree.node[1]).1, &mut w);
+        let w: Vec<T> = w.into_iter().zip(y).map(|(w, y)| y.clone() / w).collect();
+        tree.combine(1, 0, x.len(), &w).normalized()
+    }
+}
+
+struct SubproductTree<T>
+where
+    T: Field,
+{
+    // node[i] は区間 [l, r) に対応する Π_{l<=j<r} (X - x_j)
+    node: Vec<Polynomial<T>>,
+}
+
+impl<T> SubproductTree<T>
+where
+    T: Field + Clone,
+{
+    fn new(x: &[T]) -> Self {
+        let mut tree = Self {
+            node: vec![Polynomial::new(); 4 * x.len()],
+        };
+        tree.build(1, 0, x.len(), x);
+        tree
+    }
+
+    fn build(&mut self, i: usize, l: usize, r: usize, x: &[T]) {
+        self.node[i] = if r - l == 1 {
+            vec![-x[l].clone(), one()].into()
+        } else {
+            let m = (l + r) / 2;
+            self.build(2 * i, l, m, x);
+            self.build(2 * i + 1, m, r, x);
+            self.node[2 * i].clone() * self.node[2 * i + 1].clone()
+        };
+    }
+
+    // f は node[i] で割った余り
+    fn evaluate(&self, i: usize, l: usize, r: usize, f: Polynomial<T>, res: &mut Vec<T>) {
+        if r - l == 1 {
+            res.push(f.coef.into_iter().next().unwrap_or_else(T::zero));
+            return;
+        }
+        let m = (l + r) / 2;
+        self.evaluate(2 * i, l, m, f.div_rem(&self.node[2 * i]).1, res);
+        self.evaluate(2 * i + 1, m, r, f.div_rem(&self.node[2 * i + 1]).1, res);
+    }
+
+    // Σ_{l<=j<r} w_j Π_{l<=k<r, k≠j} (X - x_k)
+    fn combine(&self, i: usize, l: usize, r: usize, w: &[T]) -> Polynomial<T> {
+        if r - l == 1 {
+            return w[l].clone().into();
+        }
+        let m = (l + r) / 2;
+        self.combine(2 * i, l, m, w) * self.node[2 * i + 1].clone()
+            + self.combine(2 * i + 1, m, r, w) * self.node[2 * i].clone()
+    }
+}
+
+#[test]
+fn test_multipoint_evaluation() {
+    use crate::other::algebraic::Zero;
+    use crate::other::fp::{Fp, ModInt};
+    use crate::other::rand::{rand_int, random};
+
+    fn test<const M: u32>(n: usize, m: usize) {
+        let f: Polynomial<ModInt<M>> = (0..m).map(|_| random()).collect();
+        let x: Vec<ModInt<M>> = (0..n).map(|_| random()).collect();
+        let y: Vec<ModInt<M>> = x.iter().map(|x| f.evaluate(x)).collect();
+        assert_eq!(f.multipoint_evaluate(&x), y);
+
+        let mut x = x;
+        x.sort_by_key(|x| x.0);
+        x.dedup();
+        let g: Polynomial<ModInt<M>> = (0..x.len()).map(|_| random()).collect();
+        let y: Vec<ModInt<M>> = x.iter().map(|x| g.evaluate(x)).collect();
+        let mut h = Polynomial::interpolate(&x, &y);
+        h.coef.resize(x.len(), ModInt::zero());
+        assert_eq!(h.coef, g.coef);
+    }
+
+    for _ in 0..100 {
+        let (n, m) = (rand_int(0..20), rand_int(0..20));
+        test::<998244353>(n, m);
+        test::<13>(n, m);
+    }
+    for _ in 0..3 {
+        test::<998244353>(rand_int(0..1000), rand_int(0..1000));
+    }
+
+    let x: Vec<Fp> = (0..5).map(Fp::from).collect();
+    let y: Vec<Fp> = (0..5).map(|i: u32| Fp::from(i * i)).collect();
+    let f = Polynomial::interpolate(&x, &y);
+    assert_eq!(f.coef, vec![Fp::new(0), Fp::new(0), Fp::new(1)]);
+}
@@ -30,7 +30,22 @@ where
         self
     }
 
-    pub fn evaluate<U>(self, x: &U) -> U
+    pub(crate) fn prefix(&self, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.coef.iter().take(len).cloned().collect()
+    }
+
+    pub(crate) fn truncated(mut self, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.coef.resize(len, zero());
+        self
+    }
+
+    pub fn evaluate<U>(&self, x: &U) -> U
     where
         T: Mul<U, Output = U> + Clone,
         U: Semiring + Clone,
@@ -38,11 +53,26 @@ where
         let mut res: U = zero();
         let mut pow: U = one();
         for c in self {
-            res += c * pow.clone();
+            res += c.clone() * pow.clone();
             pow = pow * x.clone();
         }
         res
     }
+
+    pub fn derivative(&self) -> Self
+    where
+        T: Semiring + Clone,
+    {
+        let mut i: T = zero();
+        self.coef
+            .iter()
+            .skip(1)
+            .map(|c| {
+                i += one();
+                c.clone() * i.clone()
+            })
+            .collect()
+    }
 }
 
 impl<T> Add<Self> for Polynomial<T>
@@ -86,6 +116,14 @@ where
     fn mul(self, right: Self) -> Self {
         let n = self.coef.len();
         let m = right.coef.len();
+        if n == 0 || m == 0 {
+            return Self::new();
+        }
+        if n.min(m) > NAIVE_THRESHOLD {
+            if let Some(coef) = T::convolution(&self.coef, &right.coef) {
+                return Self { coef };
+            }
+        }
         let mut res = vec![zero::<T>(); n + m - 1];
         for (i, a) in enumerate(&self) {
             for (j, b) in enumerate(&right) {
@@ -96,6 +134,9 @@ where
     }
 }
 
+// これより短い場合は One::convolution を用いず愚直に計算する
+const NAIVE_THRESHOLD: usize = 32;
+
 impl<T> Mul<T> for Polynomial<T>
 where
     T: Semiring + Clone,
@@ -273,3 +314,75 @@ where
         Ok(())
     }
 }
+
+#[test]
+fn test_polynomial_mul() {
+    use crate::other::fp::{Fp, ModInt};
+    use crate::other::rand::{rand_int, random};
+    use std::num::Wrapping;
+
+    fn test<T>(n: usize, m: usize)
+    where
+        T: Semiring + Clone + Eq + Debug,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let a: Polynomial<T> = (0..n).map(|_| random()).collect();
+        let b: Polynomial<T> = (0..m).map(|_| random()).collect();
+        let mut c = vec![zero::<T>(); if n == 0 || m == 0 { 0 } else { n + m - 1 }];
+        for (i, x) in enumerate(&a) {
+            for (j, y) in enumerate(&b) {
+                c[i + j] += x.clone() * y.clone();
+            }
+        }
+        assert_eq!((a * b).coef, c);
+    }
+
+    for &(n_max, q) in &[(5, 100), (100, 30), (1000, 3)] {
+        for _ in 0..q {
+            let n = rand_int(0..n_max);
+            let m = rand_int(0..n_max);
+            test::<Fp>(n, m);
+            test::<ModInt<1_000_000_007>>(n, m);
+            test::<ModInt<13>>(n, m);
+            test::<Wrapping<i64>>(n, m);
+            test::<ModInt<7340033>>(n, m);
+            test::<ModInt<2>>(n, m);
+            test::<crate::other::GF2m>(n, m);
+        }
+    }
+
+    // 2^((M - 1) の 2 進付値) や three_prime_convolution の上限を超える長さ
+    fn test_long<T>(n: usize, m: usize)
+    where
+        T: Semiring + Clone + Eq + Debug,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let a: Polynomial<T> = (0..n).map(|_| random()).collect();
+        let b: Polynomial<T> = (0..m).map(|_| random()).collect();
+        let x: T = random();
+        let (s, t) = (a.evaluate(&x), b.evaluate(&x));
+        let c = a * b;
+        assert_eq!(c.coef.len(), n + m - 1);
+        assert_eq!(c.evaluate(&x), s * t);
+    }
+
+    test_long::<ModInt<65537>>(40000, 40000);
+    test_long::<ModInt<1_000_000_007>>(40000, 40000);
+}
+
+#[test]
+fn test_polynomial_mul_long() {
+    use crate::other::fp::Fp;
+    use crate::other::rand::random;
+
+    // three_prime_convolution の上限 2^23 を超える長さ
+    let n = (1 << 23) - 10;
+    let m = 100;
+    let a: Polynomial<Fp> = (0..n).map(|_| random()).collect();
+    let b: Polynomial<Fp> = (0..m).map(|_| random()).collect();
+    let x: Fp = random();
+    let (s, t) = (a.evaluate(&x), b.evaluate(&x));
+    let c = a * b;
+    assert_eq!(c.coef.len(), n + m - 1);
+    assert_eq!(c.evaluate(&x), s * t);
+}
@@ -0,0 +1,98 @@
+/*
+
+Reference
+
+[1] Brent, R. P., & Kung, H. T. (1978).
+    Fast algorithms for manipulating formal power series.
+    Journal of the ACM, 25(4), 581-595.
+
+
+Description
+
+T: 半環
+f, g: T 上の多項式、len(f) = m
+n: 求める項数
+
+f(g(x)) mod x^n を長さ n の Polynomial として返す。
+
+時間計算量: O(n m + sqrt(m) M(n))
+ただし M(n) は多項式乗算の計算量
+
+k = ceil(sqrt(m)) として g^0, ..., g^k を前計算し、
+f を長さ k ごとに区切った各部分に g を代入したものを g^k についての Horner 法でまとめる。
+各部分の計算は前計算した冪の線形結合をとる。
+
+*/
+
+use crate::other::algebraic::{one, Semiring};
+use crate::other::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Semiring + Clone,
+{
+    pub fn compose(&self, g: &Self, n: usize) -> Self {
+        let m = self.coef.len();
+        let mut res = Self::new().truncated(n);
+        if n == 0 || m == 0 {
+            return res;
+        }
+        let k = (1..).find(|&k| k * k >= m).unwrap();
+        let g = g.prefix(n);
+        let mut pw = vec![Self::from(one::<T>()).truncated(n)];
+        for i in 0..k {
+            let p = (pw[i].clone() * g.clone()).truncated(n);
+            pw.push(p);
+        }
+        let gk = pw.pop().unwrap();
+        for block in self.coef.chunks(k).rev() {
+            res = (res * gk.clone()).truncated(n);
+            for (c, p) in block.iter().zip(&pw) {
+                for (r, p) in res.coef.iter_mut().zip(&p.coef) {
+                    *r += c.clone() * p.clone();
+                }
+            }
+        }
+        res
+    }
+}
+
+#[test]
+fn test_polynomial_composition() {
+    use crate::other::fp::ModInt;
+    use crate::other::rand::{rand_int, random};
+
+    fn naive<const M: u32>(
+        f: &Polynomial<ModInt<M>>,
+        g: &Polynomial<ModInt<M>>,
+        n: usize,
+    ) -> Vec<ModInt<M>> {
+        let mut res = vec![ModInt::new(0); n];
+        for &c in f.coef.iter().rev() {
+            let mut next = vec![ModInt::new(0); n];
+            for (i, &r) in res.iter().enumerate() {
+                for (j, &g) in g.coef.iter().enumerate().take(n.saturating_sub(i)) {
+                    next[i + j] += r * g;
+                }
+            }
+            if n != 0 {
+                next[0] += c;
+            }
+            res = next;
+        }
+        res
+    }
+
+    fn test<const M: u32>(m: usize, l: usize, n: usize) {
+        let f: Polynomial<ModInt<M>> = (0..m).map(|_| random()).collect();
+        let g: Polynomial<ModInt<M>> = (0..l).map(|_| random()).collect();
+        assert_eq!(f.compose(&g, n).coef, naive(&f, &g, n));
+    }
+
+    for _ in 0..300 {
+        let (m, l, n) = (rand_int(0..30), rand_int(0..30), rand_int(0..30));
+        test::<998244353>(m, l, n);
+        test::<1_000_000_007>(m, l, n);
+    }
+    test::<998244353>(300, 300, 300);
+}
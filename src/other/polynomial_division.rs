@@ -0,0 +1,322 @@
+/*
+
+Reference
+
+[1] Thull, K., & Yap, C. K. (1990).
+    A unified approach to HGCD algorithms for polynomials and integers.
+    Manuscript.
+
+
+Description
+
+T: 体
+a, b: T 上の多項式
+n: deg(a) + deg(b)
+
+div_rem(a, b): a = q b + r, deg(r) < deg(b) を満たす (q, r)
+monic(a): a を最高次係数で割ったもの
+gcd(a, b): モニックな最大公約多項式、ただし gcd(0, 0) = 0
+extgcd(a, b): s a + t b = gcd(a, b) を満たす (gcd(a, b), s, t)
+
+時間計算量
+div_rem: O(M(n))
+gcd, extgcd: O(M(n) log(n))
+ただし M(n) は多項式乗算の計算量
+
+除算は小さい入力では筆算を、大きい入力では b の反転の逆元を Newton 法で求めて行う。
+gcd は half-GCD を用いて互除法の商の列をまとめて計算する。
+返す多項式は最高次係数が非零となるように正規化されている。
+
+*/
+
+use crate::other::algebraic::{one, zero, Field, Zero};
+use crate::other::Polynomial;
+use std::ops::{Div, Rem};
+
+const NAIVE_THRESHOLD: usize = 64;
+
+// [[m0, m1], [m2, m3]]
+type Mat<T> = [Polynomial<T>; 4];
+
+impl<T> Polynomial<T>
+where
+    T: Field + Clone,
+{
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let a = self.clone().normalized();
+        let b = rhs.clone().normalized();
+        assert!(!b.is_zero(), "division by zero polynomial");
+        let n = a.coef.len();
+        let m = b.coef.len();
+        if n < m {
+            return (Self::new(), a);
+        }
+        let k = n - m + 1;
+
+        if k <= NAIVE_THRESHOLD || m <= NAIVE_THRESHOLD {
+            let mut r = a.coef;
+            let mut q = vec![zero::<T>(); k];
+            let inv = T::one() / b.coef[m - 1].clone();
+            for i in (0..k).rev() {
+                let c = r[i + m - 1].clone() * inv.clone();
+                for (r, b) in r[i..].iter_mut().zip(&b.coef) {
+                    *r -= c.clone() * b.clone();
+                }
+                q[i] = c;
+            }
+            r.truncate(m - 1);
+            return (Self::from(q).normalized(), Self::from(r).normalized());
+        }
+
+        let ra: Self = a.coef.iter().rev().take(k).cloned().collect();
+        let rb: Self = b.coef.iter().rev().cloned().collect();
+        let mut q = (ra * rb.inv(k)).truncated(k);
+        q.coef.reverse();
+        let r = (a - q.clone() * b).truncated(m - 1);
+        (q.normalized(), r.normalized())
+    }
+
+    pub fn monic(self) -> Self {
+        let p = self.normalized();
+        match p.coef.last() {
+            None => p,
+            Some(c) => {
+                let inv = T::one() / c.clone();
+                p * inv
+            }
+        }
+    }
+
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        self.extgcd(rhs).0
+    }
+
+    pub fn extgcd(&self, rhs: &Self) -> (Self, Self, Self) {
+        let mut a = self.clone().normalized();
+        let mut b = rhs.clone().normalized();
+        let mut m: Mat<T> = identity();
+        if !b.is_zero() && a.coef.len() <= b.coef.len() {
+            let (q, r) = a.div_rem(&b);
+            m = step(m, q);
+            a = b;
+            b = r;
+        }
+        let m = mat_mul(&full_gcd(a, b), &m);
+        let [s, t, _, _] = m;
+        let g = (s.clone() * self.clone() + t.clone() * rhs.clone()).normalized();
+        match g.coef.last() {
+            None => (g, s, t),
+            Some(c) => {
+                let inv = T::one() / c.clone();
+                (g * inv.clone(), s * inv.clone(), t * inv)
+            }
+        }
+    }
+
+    pub(crate) fn normalized(mut self) -> Self {
+        while self.coef.last().is_some_and(|c| c.is_zero()) {
+            self.coef.pop();
+        }
+        self
+    }
+
+    // x^k で割った商
+    fn shr(&self, k: usize) -> Self {
+        self.coef.iter().skip(k).cloned().collect()
+    }
+}
+
+impl<T> Div for Polynomial<T>
+where
+    T: Field + Clone,
+{
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<T> Rem for Polynomial<T>
+where
+    T: Field + Clone,
+{
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
+fn identity<T>() -> Mat<T>
+where
+    T: Field + Clone,
+{
+    [
+        one::<T>().into(),
+        Polynomial::new(),
+        Polynomial::new(),
+        one::<T>().into(),
+    ]
+}
+
+fn mat_mul<T>(a: &Mat<T>, b: &Mat<T>) -> Mat<T>
+where
+    T: Field + Clone,
+{
+    let f = |i: usize, j: usize| {
+        (a[2 * i].clone() * b[j].clone() + a[2 * i + 1].clone() * b[2 + j].clone()).normalized()
+    };
+    [f(0, 0), f(0, 1), f(1, 0), f(1, 1)]
+}
+
+fn apply<T>(m: &Mat<T>, a: &Polynomial<T>, b: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>)
+where
+    T: Field + Clone,
+{
+    let f =
+        |i: usize| (m[2 * i].clone() * a.clone() + m[2 * i + 1].clone() * b.clone()).normalized();
+    (f(0), f(1))
+}
+
+// [[0, 1], [1, -q]] m
+fn step<T>(m: Mat<T>, q: Polynomial<T>) -> Mat<T>
+where
+    T: Field + Clone,
+{
+    let [m0, m1, m2, m3] = m;
+    let n2 = (m0 - q.clone() * m2.clone()).normalized();
+    let n3 = (m1 - q * m3.clone()).normalized();
+    [m2, m3, n2, n3]
+}
+
+// deg(a) > deg(b) に対し、M (a, b) = (c, d) が deg(c) >= ceil(deg(a) / 2) > deg(d) を満たす M
+fn half_gcd<T>(a: Polynomial<T>, b: Polynomial<T>) -> Mat<T>
+where
+    T: Field + Clone,
+{
+    let n = a.coef.len() - 1;
+    let m = n.div_ceil(2);
+    if b.coef.len() <= m {
+        return identity();
+    }
+
+    if n <= NAIVE_THRESHOLD {
+        let (mut a, mut b) = (a, b);
+        let mut r = identity();
+        while b.coef.len() > m {
+            let (q, c) = a.div_rem(&b);
+            r = step(r, q);
+            a = b;
+            b = c;
+        }
+        return r;
+    }
+
+    let r = half_gcd(a.shr(m), b.shr(m));
+    let (c, d) = apply(&r, &a, &b);
+    if d.coef.len() <= m {
+        return r;
+    }
+    let (q, e) = c.div_rem(&d);
+    let r = step(r, q);
+    if e.coef.len() <= m {
+        return r;
+    }
+    let k = 2 * m - (d.coef.len() - 1);
+    mat_mul(&half_gcd(d.shr(k), e.shr(k)), &r)
+}
+
+// deg(a) > deg(b) に対し、M (a, b) = (gcd, 0) を満たす M
+fn full_gcd<T>(a: Polynomial<T>, b: Polynomial<T>) -> Mat<T>
+where
+    T: Field + Clone,
+{
+    if b.is_zero() {
+        return identity();
+    }
+    let r = half_gcd(a.clone(), b.clone());
+    let (c, d) = apply(&r, &a, &b);
+    if d.is_zero() {
+        return r;
+    }
+    let (q, e) = c.div_rem(&d);
+    let r = step(r, q);
+    if e.is_zero() {
+        return r;
+    }
+    mat_mul(&full_gcd(d, e), &r)
+}
+
+#[test]
+fn test_polynomial_division() {
+    use crate::other::fp::{Fp, ModInt};
+    use crate::other::rand::{rand_int, random};
+
+    fn naive_gcd<T>(a: Polynomial<T>, b: Polynomial<T>) -> Polynomial<T>
+    where
+        T: Field + Clone,
+    {
+        let (mut a, mut b) = (a.normalized(), b.normalized());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    fn test<const M: u32>(n: usize, m: usize, g: usize) {
+        type P<const M: u32> = Polynomial<ModInt<M>>;
+        let gen = |n: usize| -> P<M> { (0..n).map(|_| random()).collect() };
+        let c = gen(g);
+        let a = gen(n) * c.clone();
+        let b = gen(m) * c.clone();
+
+        if !b.clone().normalized().is_zero() {
+            let (q, r) = a.div_rem(&b);
+            assert!(r.coef.len() < b.clone().normalized().coef.len());
+            assert_eq!(
+                (q.clone() * b.clone() + r.clone()).normalized().coef,
+                a.clone().normalized().coef
+            );
+            assert_eq!((a.clone() / b.clone()).coef, q.coef);
+            assert_eq!((a.clone() % b.clone()).coef, r.coef);
+        }
+
+        let (h, s, t) = a.extgcd(&b);
+        assert_eq!(h.coef, naive_gcd(a.clone(), b.clone()).coef);
+        assert_eq!(h.coef, a.gcd(&b).coef);
+        assert_eq!(
+            (s.clone() * a.clone() + t.clone() * b.clone())
+                .normalized()
+                .coef,
+            h.coef
+        );
+        if !h.is_zero() {
+            let (da, db, dh) = (
+                a.clone().normalized().coef.len(),
+                b.clone().normalized().coef.len(),
+                h.coef.len(),
+            );
+            assert!(s.coef.len() <= db.saturating_sub(dh).max(1));
+            assert!(t.coef.len() <= da.saturating_sub(dh).max(1));
+        }
+    }
+
+    for _ in 0..300 {
+        let (n, m, g) = (rand_int(0..20), rand_int(0..20), rand_int(0..5));
+        test::<998244353>(n, m, g);
+        test::<2>(n, m, g);
+        test::<5>(n, m, g);
+    }
+    for _ in 0..5 {
+        let (n, m, g) = (rand_int(0..500), rand_int(0..500), rand_int(0..300));
+        test::<998244353>(n, m, g);
+        test::<3>(n, m, g);
+    }
+
+    let x: Polynomial<Fp> = vec![Fp::new(0), Fp::new(1)].into();
+    let one: Polynomial<Fp> = Fp::new(1).into();
+    assert_eq!(x.clone().monic().coef, x.coef);
+    assert_eq!(x.gcd(&one).coef, one.coef);
+    assert!(Polynomial::<Fp>::new().gcd(&Polynomial::new()).is_zero());
+}
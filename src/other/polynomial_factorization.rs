@@ -0,0 +1,342 @@
+/*
+
+Reference
+
+[1] Cantor, D. G., & Zassenhaus, H. (1981).
+    A new algorithm for factoring polynomials over finite fields.
+    Mathematics of Computation, 587-592.
+
+[2] Rabin, M. O. (1980).
+    Probabilistic algorithms in finite fields.
+    SIAM Journal on computing, 9(2), 273-280.
+
+
+Description
+
+f: F_M 上の n 次多項式、f ≠ 0
+
+pow_mod(f, k, g): f^k mod g
+squarefree_decomposition(f): f のモニックな無平方分解 [(g_i, i)]
+                             f = c Π g_i^i を満たし、g_i は無平方で互いに素
+distinct_degree_factorization(f): f がモニックかつ無平方のとき、
+                                  d 次既約因子の積 h_d を並べた [(h_d, d)]
+equal_degree_factorization(f, d): f がモニックかつ無平方で既約因子がすべて d 次のとき、
+                                  その既約因子の列
+factorize(f): f のモニックな既約因子とその重複度の列
+roots(f): f の根を重複なく昇順に並べた列
+is_irreducible(f): f が既約か
+irreducible(n): ランダムなモニックの n 次既約多項式
+
+時間計算量 (期待値)
+factorize: O(n M(n) log(n) (n + log(M)))
+roots: O(M(n) log(n) log(M))
+is_irreducible: O(M(n) (n log(M) + log(n)^2))
+irreducible: O(n M(n) (n log(M) + log(n)^2))
+ただし M(n) は多項式乗算の計算量
+
+無平方分解は f' との gcd をとっていき、重複度が M の倍数の部分は M 乗根をとって再帰する。
+次数別分解は x^(M^d) - x が d の約数次の既約多項式すべての積であることを用いる。
+同次分解はランダムな a に対して a^((M^d - 1) / 2) - 1 (M = 2 のときは a のトレース) と
+f の gcd をとって f を分割していく。
+既約性判定は Rabin のテストを用いる。
+
+ランダムな n 次モニック多項式が既約である確率はおよそ 1 / n なので、
+GF2m や ExtensionField の法となる既約多項式を探すことにも使える。
+
+*/
+
+use crate::other::algebraic::{One, Zero};
+use crate::other::factorize::factorize;
+use crate::other::fp::ModInt;
+use crate::other::rand::random;
+use crate::other::Polynomial;
+
+type Poly<const M: u32> = Polynomial<ModInt<M>>;
+
+impl<const M: u32> Polynomial<ModInt<M>> {
+    pub fn pow_mod(&self, mut k: u64, g: &Self) -> Self {
+        let mut base = self.div_rem(g).1;
+        let mut res = Self::from(ModInt::one()).div_rem(g).1;
+        while k != 0 {
+            if k & 1 != 0 {
+                res = (res * base.clone()).div_rem(g).1;
+            }
+            base = (base.clone() * base).div_rem(g).1;
+            k /= 2;
+        }
+        res
+    }
+
+    pub fn squarefree_decomposition(&self) -> Vec<(Self, u32)> {
+        let f = self.clone().monic();
+        assert!(!f.is_zero());
+        let mut res = Vec::new();
+        let mut c = f.gcd(&f.derivative());
+        let mut w = f.div_rem(&c).0;
+        let mut i = 1;
+        while w.coef.len() > 1 {
+            let y = w.gcd(&c);
+            let g = w.div_rem(&y).0;
+            if g.coef.len() > 1 {
+                res.push((g, i));
+            }
+            c = c.div_rem(&y).0;
+            w = y;
+            i += 1;
+        }
+        if c.coef.len() > 1 {
+            // F_M では a^M = a なので、c(x) = d(x)^M となる d は c の係数を間引けば得られる
+            let d: Self = c.coef.into_iter().step_by(M as usize).collect();
+            res.extend(
+                d.squarefree_decomposition()
+                    .into_iter()
+                    .map(|(g, i)| (g, i * M)),
+            );
+        }
+        res.sort_by_key(|&(_, i)| i);
+        res
+    }
+
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let mut f = self.clone().monic();
+        assert!(!f.is_zero());
+        let x: Self = vec![ModInt::zero(), ModInt::one()].into();
+        let mut res = Vec::new();
+        let mut h = x.div_rem(&f).1;
+        let mut d = 1;
+        while f.coef.len() > 2 * d {
+            h = h.pow_mod(M.into(), &f);
+            let g = f.gcd(&(h.clone() - x.clone()));
+            if g.coef.len() > 1 {
+                f = f.div_rem(&g).0;
+                h = h.div_rem(&f).1;
+                res.push((g, d));
+            }
+            d += 1;
+        }
+        if f.coef.len() > 1 {
+            let d = f.coef.len() - 1;
+            res.push((f, d));
+        }
+        res
+    }
+
+    pub fn equal_degree_factorization(&self, d: usize) -> Vec<Self> {
+        let f = self.clone().monic();
+        assert!(d >= 1 && (f.coef.len() - 1).is_multiple_of(d));
+        let mut res = Vec::new();
+        edf(f, d, &mut res);
+        res
+    }
+
+    pub fn factorize(&self) -> Vec<(Self, u32)> {
+        let mut res = Vec::new();
+        for (g, i) in self.squarefree_decomposition() {
+            for (h, d) in g.distinct_degree_factorization() {
+                for e in h.equal_degree_factorization(d) {
+                    res.push((e, i));
+                }
+            }
+        }
+        res.sort_by_cached_key(|(f, _)| {
+            (
+                f.coef.len(),
+                f.coef.iter().rev().map(|c| c.0).collect::<Vec<_>>(),
+            )
+        });
+        res
+    }
+
+    pub fn roots(&self) -> Vec<ModInt<M>> {
+        let f = self.clone().monic();
+        assert!(!f.is_zero());
+        let x: Self = vec![ModInt::zero(), ModInt::one()].into();
+        let g = f.gcd(&(x.pow_mod(M.into(), &f) - x));
+        if g.coef.len() <= 1 {
+            return Vec::new();
+        }
+        let mut res: Vec<ModInt<M>> = g
+            .equal_degree_factorization(1)
+            .into_iter()
+            .map(|h| -h.coef[0])
+            .collect();
+        res.sort_by_key(|x| x.0);
+        res
+    }
+
+    pub fn is_irreducible(&self) -> bool {
+        let f = self.clone().monic();
+        let n = match f.degree() {
+            None | Some(0) => return false,
+            Some(n) => n,
+        };
+        let x: Self = vec![ModInt::zero(), ModInt::one()].into();
+        let ks: Vec<usize> = factorize(n as u64)
+            .into_iter()
+            .map(|(q, _)| n / q as usize)
+            .collect();
+        // h = x^(M^k) mod f を k の昇順に一度ずつ求める
+        let mut h = x.div_rem(&f).1;
+        for k in 1..=n {
+            h = h.pow_mod(M.into(), &f);
+            if ks.contains(&k) && f.gcd(&(h.clone() - x.clone())).coef.len() != 1 {
+                return false;
+            }
+        }
+        (h - x).div_rem(&f).1.is_zero()
+    }
+
+    pub fn irreducible(n: usize) -> Self {
+        assert!(n >= 1);
+        loop {
+            let mut f: Self = (0..n).map(|_| random()).collect();
+            f.coef.push(ModInt::one());
+            if f.is_irreducible() {
+                return f;
+            }
+        }
+    }
+}
+
+// f はモニックかつ無平方で、既約因子がすべて d 次
+fn edf<const M: u32>(f: Poly<M>, d: usize, res: &mut Vec<Poly<M>>) {
+    let n = f.coef.len() - 1;
+    if n == 0 {
+        return;
+    }
+    if n == d {
+        res.push(f);
+        return;
+    }
+    loop {
+        let a: Poly<M> = (0..n).map(|_| random()).collect();
+        let b = if M == 2 {
+            // a + a^2 + ... + a^(2^(d-1))
+            let mut t = a.div_rem(&f).1;
+            let mut s = t.clone();
+            for _ in 1..d {
+                t = t.pow_mod(2, &f);
+                s += t.clone();
+            }
+            s
+        } else {
+            // (a^(1 + M + ... + M^(d-1)))^((M-1)/2) - 1
+            let mut t = a.div_rem(&f).1;
+            let mut s = t.clone();
+            for _ in 1..d {
+                t = t.pow_mod(M.into(), &f);
+                s = (s * t.clone()).div_rem(&f).1;
+            }
+            s.pow_mod(((M - 1) / 2).into(), &f) - ModInt::one().into()
+        };
+        let g = f.gcd(&b);
+        if g.coef.len() > 1 && g.coef.len() < f.coef.len() {
+            let h = f.div_rem(&g).0;
+            edf(g, d, res);
+            edf(h, d, res);
+            return;
+        }
+    }
+}
+
+#[test]
+fn test_polynomial_factorization() {
+    use crate::other::fp::Fp;
+    use crate::other::rand::rand_int;
+
+    fn test<const M: u32>(n: usize) {
+        let f: Poly<M> = (0..n + 1).map(|_| random()).collect();
+        let f = f.normalized();
+        if f.is_zero() {
+            return;
+        }
+        let c = *f.coef.last().unwrap();
+
+        let sq = f.squarefree_decomposition();
+        let mut prod: Poly<M> = c.into();
+        for (g, i) in &sq {
+            assert_eq!(g.coef.last(), Some(&ModInt::one()));
+            assert_eq!(g.gcd(&g.derivative()).coef.len(), 1);
+            for _ in 0..*i {
+                prod = prod * g.clone();
+            }
+        }
+        assert_eq!(prod.normalized().coef, f.coef);
+
+        let fac = f.factorize();
+        let mut prod: Poly<M> = c.into();
+        for (g, i) in &fac {
+            assert!(g.is_irreducible());
+            assert_eq!(g.coef.last(), Some(&ModInt::one()));
+            for _ in 0..*i {
+                prod = prod * g.clone();
+            }
+        }
+        assert_eq!(prod.normalized().coef, f.coef);
+        for w in fac.windows(2) {
+            assert_ne!(w[0].0.coef, w[1].0.coef);
+        }
+
+        let mut roots: Vec<ModInt<M>> = fac
+            .iter()
+            .filter(|(g, _)| g.coef.len() == 2)
+            .map(|(g, _)| -g.coef[0])
+            .collect();
+        roots.sort_by_key(|x| x.0);
+        assert_eq!(f.roots(), roots);
+        for r in &roots {
+            assert!(f.evaluate(r).is_zero());
+        }
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..12);
+        test::<2>(n);
+        test::<3>(n);
+        test::<5>(n);
+        test::<998244353>(n);
+    }
+
+    // (x - 1)^3 (x^2 + 1) (x^2 + x + 1) over F_3
+    {
+        type P = Poly<3>;
+        let p = |c: &[u32]| -> P { c.iter().map(|&c| ModInt::new(c)).collect() };
+        let f = p(&[2, 1]) * p(&[2, 1]) * p(&[2, 1]) * p(&[1, 0, 1]) * p(&[1, 1, 1]);
+        let fac = f.factorize();
+        let fac: Vec<(Vec<u32>, u32)> = fac
+            .into_iter()
+            .map(|(g, i)| (g.coef.iter().map(|c| c.0).collect(), i))
+            .collect();
+        assert_eq!(fac, vec![(vec![2, 1], 5), (vec![1, 0, 1], 1)]);
+    }
+
+    // 素数 p で x^p - x の根はすべての元
+    {
+        type P = Poly<7>;
+        let mut f: P = (0..8).map(|_| ModInt::zero()).collect();
+        f.coef[7] = ModInt::one();
+        f.coef[1] = -ModInt::one();
+        assert_eq!(f.roots(), (0..7).map(ModInt::new).collect::<Vec<_>>());
+    }
+
+    // GF2m の法 1 + x + x^30 は既約
+    {
+        use crate::other::gf2m;
+        let f: Poly<2> = (0..=gf2m::M)
+            .map(|i| ModInt::new(gf2m::MOD >> i & 1))
+            .collect();
+        assert!(f.is_irreducible());
+        let g = f.clone() * Poly::<2>::from(vec![ModInt::one(), ModInt::one()]);
+        assert!(!g.is_irreducible());
+    }
+
+    for n in 1..10 {
+        let f = Poly::<2>::irreducible(n);
+        assert_eq!(f.degree(), Some(n));
+        assert_eq!(f.factorize().len(), 1);
+        let f = Polynomial::<Fp>::irreducible(n);
+        let fac = f.factorize();
+        assert_eq!(fac.len(), 1);
+        assert_eq!((&fac[0].0.coef, fac[0].1), (&f.coef, 1));
+    }
+}
@@ -0,0 +1,116 @@
+/*
+
+Description
+
+p: 素数
+
+order(p, a): a の位数、すなわち a^k ≡ 1 (mod p) を満たす最小の正整数 k
+primitive_root(p): 最小の原始根
+primitive_root_u32(p): primitive_root の const fn 版
+
+時間計算量: 期待 O(p^(1/4) log(p) + log(p)^2)
+
+p - 1 を素因数分解し、各素因数 q について a^((p-1)/q) を調べる。
+素因数分解は factorize で行っている。
+primitive_root_u32 は const fn のため試し割りで行っている。
+
+*/
+
+use crate::other::factorize::factorize;
+use crate::other::mod_pow::mod_pow;
+
+pub fn order(p: u64, a: u64) -> u64 {
+    let a = a % p;
+    assert_ne!(a, 0);
+    let mut o = p - 1;
+    for q in prime_factors(p - 1) {
+        while o.is_multiple_of(q) && mod_pow(p, a, o / q) == 1 {
+            o /= q;
+        }
+    }
+    o
+}
+
+pub fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let qs = prime_factors(p - 1);
+    (2..)
+        .find(|&g| qs.iter().all(|&q| mod_pow(p, g, (p - 1) / q) != 1))
+        .unwrap()
+}
+
+fn prime_factors(n: u64) -> Vec<u64> {
+    factorize(n).into_iter().map(|(q, _)| q).collect()
+}
+
+pub const fn primitive_root_u32(p: u32) -> u32 {
+    const fn pow(p: u64, mut a: u64, mut exp: u64) -> u64 {
+        let mut res = 1;
+        while exp != 0 {
+            if exp & 1 != 0 {
+                res = res * a % p;
+            }
+            a = a * a % p;
+            exp /= 2;
+        }
+        res
+    }
+
+    if p == 2 {
+        return 1;
+    }
+    let p = p as u64;
+    let mut qs = [0; 32];
+    let mut len = 0;
+    let mut n = p - 1;
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            qs[len] = d;
+            len += 1;
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n != 1 {
+        qs[len] = n;
+        len += 1;
+    }
+
+    let mut g = 2;
+    loop {
+        let mut i = 0;
+        while i < len && pow(p, g, (p - 1) / qs[i]) != 1 {
+            i += 1;
+        }
+        if i == len {
+            return g as u32;
+        }
+        g += 1;
+    }
+}
+
+#[test]
+fn test_primitive_root() {
+    for p in (2..300u64).filter(|&p| (2..p).all(|d| p % d != 0)) {
+        for a in 1..p {
+            let naive = (1..p).find(|&k| mod_pow(p, a, k) == 1).unwrap();
+            assert_eq!(order(p, a), naive);
+        }
+        let g = primitive_root(p);
+        assert_eq!(order(p, g), p - 1);
+        assert!((1..g).all(|a| order(p, a) != p - 1));
+        assert_eq!(primitive_root_u32(p as u32) as u64, g);
+    }
+
+    assert_eq!(primitive_root(998244353), 3);
+    assert_eq!(primitive_root(1_000_000_007), 5);
+    assert_eq!(primitive_root(754974721), 11);
+    assert_eq!(primitive_root(167772161), 3);
+    assert_eq!(primitive_root(469762049), 3);
+    assert_eq!(primitive_root_u32(754974721), 11);
+}
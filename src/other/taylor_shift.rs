@@ -0,0 +1,74 @@
+/*
+
+Description
+
+f: F_M 上の多項式、長さ n < M
+c: F_M の元
+
+f(x + c) を長さ n の Polynomial として返す。
+
+時間計算量: O(M(n))
+ただし M(n) は多項式乗算の計算量
+
+[x^k] f(x + c) = (1 / k!) Σ_{i>=k} (f_i i!) (c^(i-k) / (i-k)!) なので、
+f_i i! を反転した列と c^j / j! の畳み込みで求まる。
+
+*/
+
+use crate::other::algebraic::One;
+use crate::other::fp::ModInt;
+use crate::other::fp_utils::FpUtils;
+use crate::other::Polynomial;
+
+impl<const M: u32> Polynomial<ModInt<M>> {
+    pub fn taylor_shift(&self, c: ModInt<M>) -> Self {
+        let n = self.coef.len();
+        let utils = FpUtils::<M>::new(n);
+        let a: Self = (0..n).rev().map(|i| self.coef[i] * utils.fact(i)).collect();
+        let mut p = ModInt::one();
+        let b: Self = (0..n)
+            .map(|j| {
+                let r = p * utils.inv_fact(j);
+                p *= c;
+                r
+            })
+            .collect();
+        let d = (a * b).truncated(n);
+        (0..n)
+            .map(|k| d.coef[n - 1 - k] * utils.inv_fact(k))
+            .collect()
+    }
+}
+
+#[test]
+fn test_taylor_shift() {
+    use crate::other::rand::{rand_int, random};
+
+    fn naive<const M: u32>(f: &Polynomial<ModInt<M>>, c: ModInt<M>) -> Vec<ModInt<M>> {
+        let n = f.coef.len();
+        let mut res = vec![ModInt::new(0); n];
+        // Horner 法で f(x + c) を展開する
+        for &a in f.coef.iter().rev() {
+            for i in (0..n).rev() {
+                let t = if i == 0 { ModInt::new(0) } else { res[i - 1] };
+                res[i] = res[i] * c + t;
+            }
+            res[0] += a;
+        }
+        res
+    }
+
+    fn test<const M: u32>(n: usize) {
+        let f: Polynomial<ModInt<M>> = (0..n).map(|_| random()).collect();
+        let c: ModInt<M> = random();
+        assert_eq!(f.taylor_shift(c).coef, naive(&f, c));
+        assert_eq!(f.taylor_shift(ModInt::new(0)).coef, f.coef);
+    }
+
+    for _ in 0..100 {
+        let n = rand_int(0..50);
+        test::<998244353>(n);
+        test::<1_000_000_007>(n);
+    }
+    test::<998244353>(1000);
+}